@@ -1,4 +1,5 @@
 use solana_program::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 
 // =============================================================================
 // PDA SEEDS
@@ -13,6 +14,39 @@ pub const TREASURY: &[u8] = b"treasury";
 /// Seed for Task PDA: ["task", task_id.to_le_bytes()]
 pub const TASK: &[u8] = b"task";
 
+/// Seed for Agent PDA: ["agent", wallet]
+pub const AGENT: &[u8] = b"agent";
+
+/// Seed for Milestone PDA: ["milestone", task_id.to_le_bytes(), index]
+pub const MILESTONE: &[u8] = b"milestone";
+
+/// Seed for Application PDA: ["application", task_id.to_le_bytes(), applicant]
+pub const APPLICATION: &[u8] = b"application";
+
+/// Seed for contest Submission PDA: ["submission", task_id.to_le_bytes(), entrant]
+pub const SUBMISSION: &[u8] = b"submission";
+
+/// Seed for Slot PDA: ["slot", task_id.to_le_bytes(), slot_index.to_le_bytes()]
+pub const SLOT: &[u8] = b"slot";
+
+/// Seed for Arbiter PDA: ["arbiter", arbiter_index.to_le_bytes()]
+pub const ARBITER: &[u8] = b"arbiter";
+
+/// Seed for Dispute PDA: ["dispute", task_id.to_le_bytes()]
+pub const DISPUTE: &[u8] = b"dispute";
+
+/// Seed for Contribution PDA: ["contribution", task_id.to_le_bytes(), funder]
+pub const CONTRIBUTION: &[u8] = b"contribution";
+
+/// Seed for Category PDA: ["category", category_id.to_le_bytes()]
+pub const CATEGORY: &[u8] = b"category";
+
+/// Seed for Template PDA: ["template", template_id.to_le_bytes()]
+pub const TEMPLATE: &[u8] = b"template";
+
+/// Seed for Vault PDA: ["vault", vault_id.to_le_bytes()]
+pub const VAULT: &[u8] = b"vault";
+
 // =============================================================================
 // PROTOCOL CONSTANTS
 // =============================================================================
@@ -27,13 +61,77 @@ pub const TOTAL_BPS: u64 = 10_000;
 pub const DEFAULT_DISPUTE_STAKE: u64 = 100_000_000;
 
 /// Minimum bounty in lamports (0.001 SOL)
+/// Token bounties only need to be non-zero, since decimals vary by mint.
 pub const MIN_BOUNTY: u64 = 1_000_000;
 
+/// Minimum IncreaseBounty top-up in lamports (0.001 SOL)
+/// Token top-ups only need to be non-zero, since decimals vary by mint.
+pub const MIN_CONTRIBUTION: u64 = 1_000_000;
+
 /// Auto-release timeout in seconds (48 hours)
 /// If creator doesn't approve/reject within this time after submission,
 /// anyone can call ClaimExpired to release escrow to the worker.
 pub const AUTO_RELEASE_TIMEOUT: i64 = 48 * 60 * 60;
 
+/// Dispute window in seconds (24 hours)
+/// After a rejection, only the rejected claimer may dispute within this window.
+/// Once it lapses, anyone can call ReopenTask to return the task to OPEN.
+pub const DISPUTE_WINDOW: i64 = 24 * 60 * 60;
+
+/// Default work window in seconds (72 hours)
+/// A claim with no submission after this long can be released back to OPEN.
+pub const DEFAULT_WORK_WINDOW: i64 = 72 * 60 * 60;
+
+/// Deadline grace period in seconds (1 hour)
+/// A claimer may still submit work this long after the task deadline; after it,
+/// anyone can call ExpireTask to refund the creator.
+pub const DEADLINE_GRACE_PERIOD: i64 = 60 * 60;
+
+/// Contest award window in seconds (14 days)
+/// How long after its deadline the creator of a contest with entries has to
+/// award it. Past this window AwardContest is closed and anyone can split the
+/// bounty equally among the entrants, one ClaimContestShare per entry.
+pub const CONTEST_AWARD_WINDOW: i64 = 14 * 24 * 60 * 60;
+
+/// Minimum interval between scheduled tasks in seconds (1 hour)
+pub const MIN_VAULT_INTERVAL: i64 = 60 * 60;
+
+/// Maximum number of milestones per task
+pub const MAX_MILESTONES: u8 = 8;
+
+/// Maximum number of arbiters assigned to a dispute
+pub const MAX_DISPUTE_ARBITERS: usize = 5;
+
+/// Default number of arbiters assigned to each dispute
+pub const DEFAULT_ARBITERS_PER_DISPUTE: u8 = 3;
+
+/// Voting period in seconds (72 hours)
+/// Once it lapses, FinalizeDispute settles on the plurality of cast votes.
+pub const DISPUTE_VOTING_PERIOD: i64 = 72 * 60 * 60;
+
+/// Counter-stake window in seconds (24 hours)
+/// The creator must match the claimer's dispute stake within this window of the
+/// dispute opening, or the claimer wins by default.
+pub const COUNTER_STAKE_WINDOW: i64 = 24 * 60 * 60;
+
+/// Default dispute resolution timeout in seconds (7 days)
+/// A dispute still unresolved this long after it was opened can be expired by
+/// anyone with ExpireDispute, refunding both sides.
+pub const DEFAULT_DISPUTE_RESOLUTION_TIMEOUT: i64 = 7 * 24 * 60 * 60;
+
+/// Share of a forfeited dispute stake paid to the arbiters who voted for the
+/// outcome (5000 = 50%); the rest goes to the treasury
+pub const ARBITER_STAKE_SHARE_BPS: u64 = 5_000;
+
+/// Maximum number of ranked winners in a contest payout
+pub const MAX_CONTEST_WINNERS: usize = 8;
+
+/// Maximum depth of a claimer allowlist merkle proof (up to 65,536 claimers)
+pub const MAX_CLAIMER_PROOF_DEPTH: usize = 16;
+
+/// Maximum number of prerequisite tasks a task can depend on
+pub const MAX_TASK_DEPENDENCIES: usize = 4;
+
 // =============================================================================
 // TASK STATUS
 // =============================================================================
@@ -44,6 +142,64 @@ pub const STATUS_SUBMITTED: u8 = 2;
 pub const STATUS_COMPLETED: u8 = 3;
 pub const STATUS_CANCELLED: u8 = 4;
 pub const STATUS_DISPUTED: u8 = 5;
+pub const STATUS_REJECTED: u8 = 6;
+
+// =============================================================================
+// TASK FLAGS
+// =============================================================================
+
+/// Workers apply with an Application PDA and the creator picks one,
+/// instead of first-come ClaimTask
+pub const TASK_FLAG_APPLICATIONS: u8 = 1 << 0;
+
+/// Contest: any number of agents submit entries until the deadline and the
+/// creator awards ranked winners with AwardContest
+pub const TASK_FLAG_CONTEST: u8 = 1 << 1;
+
+/// All flags CreateTask accepts
+pub const TASK_FLAGS_ALL: u8 = TASK_FLAG_APPLICATIONS | TASK_FLAG_CONTEST;
+
+// =============================================================================
+// TAGS (see `crate::tags::Tags` for the byte layout)
+// =============================================================================
+
+/// Maximum number of skill ids in a task's tags
+pub const MAX_TAG_SKILLS: usize = 6;
+
+/// Task needs a fast turnaround
+pub const TAG_FLAG_URGENT: u16 = 1 << 0;
+
+/// Task is suitable for agents without a track record
+pub const TAG_FLAG_BEGINNER: u16 = 1 << 1;
+
+/// Work product will be published under an open-source license
+pub const TAG_FLAG_OPEN_SOURCE: u16 = 1 << 2;
+
+/// All tag flags CreateTask accepts
+pub const TAG_FLAGS_ALL: u16 = TAG_FLAG_URGENT | TAG_FLAG_BEGINNER | TAG_FLAG_OPEN_SOURCE;
+
+// =============================================================================
+// MILESTONE STATUS
+// =============================================================================
+
+pub const MILESTONE_PENDING: u8 = 0;
+pub const MILESTONE_SUBMITTED: u8 = 1;
+pub const MILESTONE_RELEASED: u8 = 2;
+
+// =============================================================================
+// SLOT STATUS
+// =============================================================================
+
+pub const SLOT_CLAIMED: u8 = 0;
+pub const SLOT_SUBMITTED: u8 = 1;
+
+// =============================================================================
+// ARBITER VOTES
+// =============================================================================
+
+pub const VOTE_NONE: u8 = 0;
+pub const VOTE_CREATOR: u8 = 1;
+pub const VOTE_CLAIMER: u8 = 2;
 
 // =============================================================================
 // PDA HELPER FUNCTIONS
@@ -63,3 +219,70 @@ pub fn treasury_pda() -> (Pubkey, u8) {
 pub fn task_pda(task_id: u64) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[TASK, &task_id.to_le_bytes()], &crate::ID)
 }
+
+/// Derives the Agent PDA address for a wallet: ["agent", wallet]
+pub fn agent_pda(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AGENT, wallet.as_ref()], &crate::ID)
+}
+
+/// Derives the Milestone PDA address: ["milestone", task_id, index]
+pub fn milestone_pda(task_id: u64, index: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MILESTONE, &task_id.to_le_bytes(), &[index]], &crate::ID)
+}
+
+/// Derives the Application PDA address: ["application", task_id, applicant]
+pub fn application_pda(task_id: u64, applicant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[APPLICATION, &task_id.to_le_bytes(), applicant.as_ref()], &crate::ID)
+}
+
+/// Derives the contest Submission PDA address: ["submission", task_id, entrant]
+pub fn submission_pda(task_id: u64, entrant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SUBMISSION, &task_id.to_le_bytes(), entrant.as_ref()], &crate::ID)
+}
+
+/// Derives the Slot PDA address: ["slot", task_id, slot_index]
+pub fn slot_pda(task_id: u64, slot_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SLOT, &task_id.to_le_bytes(), &slot_index.to_le_bytes()], &crate::ID)
+}
+
+/// Derives the Arbiter PDA address: ["arbiter", arbiter_index]
+pub fn arbiter_pda(index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ARBITER, &index.to_le_bytes()], &crate::ID)
+}
+
+/// Derives the Dispute PDA address: ["dispute", task_id]
+pub fn dispute_pda(task_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DISPUTE, &task_id.to_le_bytes()], &crate::ID)
+}
+
+/// Derives the Contribution PDA address: ["contribution", task_id, funder]
+pub fn contribution_pda(task_id: u64, funder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONTRIBUTION, &task_id.to_le_bytes(), funder.as_ref()], &crate::ID)
+}
+
+/// Derives the Category PDA address: ["category", category_id]
+pub fn category_pda(category_id: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CATEGORY, &category_id.to_le_bytes()], &crate::ID)
+}
+
+/// Derives the Template PDA address: ["template", template_id]
+pub fn template_pda(template_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TEMPLATE, &template_id.to_le_bytes()], &crate::ID)
+}
+
+/// Derives the Vault PDA address: ["vault", vault_id]
+pub fn vault_pda(vault_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT, &vault_id.to_le_bytes()], &crate::ID)
+}
+
+/// Derives the escrow token account for a token-denominated task.
+/// This is the Task PDA's associated token account for `mint`.
+pub fn task_escrow_address(task_id: u64, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(&task_pda(task_id).0, mint, token_program)
+}
+
+/// Derives the treasury's fee account for `mint`.
+/// This is the Treasury PDA's associated token account for `mint`.
+pub fn treasury_token_address(mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(&treasury_pda().0, mint, token_program)
+}
@@ -45,6 +45,174 @@ pub enum BountyBoardError {
 
     #[error("Auto-release timeout has not elapsed yet")]
     AutoReleaseNotReady = 13,
+
+    #[error("Mint or token account does not match the task")]
+    InvalidMint = 14,
+
+    #[error("Dispute window has closed")]
+    DisputeWindowClosed = 15,
+
+    #[error("Dispute window is still open")]
+    DisputeWindowOpen = 16,
+
+    #[error("Task uses milestones; submit and approve per milestone")]
+    HasMilestones = 17,
+
+    #[error("Task has no milestones or milestone is out of order")]
+    InvalidMilestone = 18,
+
+    #[error("Milestone amounts exceed the bounty or limit reached")]
+    MilestoneLimitExceeded = 19,
+
+    #[error("Milestone amounts do not add up to the bounty")]
+    MilestonesIncomplete = 20,
+
+    #[error("Work window must not be negative")]
+    InvalidWorkWindow = 21,
+
+    #[error("Claim work window has not elapsed yet")]
+    ClaimNotStale = 22,
+
+    #[error("Protocol fee exceeds 100%")]
+    InvalidFee = 23,
+
+    #[error("Withdrawal exceeds treasury balance")]
+    InsufficientTreasury = 24,
+
+    #[error("Task only accepts applications")]
+    ApplicationRequired = 25,
+
+    #[error("Task does not accept applications")]
+    ApplicationsClosed = 26,
+
+    #[error("Price quote does not fit the bounty")]
+    InvalidQuote = 27,
+
+    #[error("Task is a contest; submit an entry instead")]
+    ContestMode = 28,
+
+    #[error("Task is not a contest")]
+    NotContest = 29,
+
+    #[error("Contest is still accepting entries")]
+    ContestOpen = 30,
+
+    #[error("Contest splits are invalid")]
+    InvalidSplits = 31,
+
+    #[error("Contest has entries and must be awarded")]
+    ContestHasEntries = 32,
+
+    #[error("Task uses slots; claim, submit and approve per slot")]
+    HasSlots = 33,
+
+    #[error("Task has no slots or slot is in the wrong state")]
+    InvalidSlot = 34,
+
+    #[error("All slots are taken")]
+    SlotsFull = 35,
+
+    #[error("Signer is not an arbiter on this dispute")]
+    NotArbiter = 36,
+
+    #[error("Arbiter has already voted")]
+    AlreadyVoted = 37,
+
+    #[error("Dispute voting period has ended")]
+    VotingClosed = 38,
+
+    #[error("Dispute has no decision yet")]
+    NoDisputeDecision = 39,
+
+    #[error("Dispute is assigned to an arbiter panel")]
+    ArbiterPanelAssigned = 40,
+
+    #[error("Invalid arbiter count")]
+    InvalidArbiterCount = 41,
+
+    #[error("Claimer share must be at most 10000 bps")]
+    InvalidClaimerShare = 42,
+
+    #[error("Dispute resolution timeout must cover the voting period")]
+    InvalidDisputeTimeout = 43,
+
+    #[error("Dispute has not reached its resolution timeout")]
+    DisputeNotExpired = 44,
+
+    #[error("Dispute has a panel decision; use FinalizeDispute")]
+    DisputeDecided = 45,
+
+    #[error("Creator has not posted a counter-stake yet")]
+    CounterStakePending = 46,
+
+    #[error("Creator has already counter-staked")]
+    AlreadyCounterStaked = 47,
+
+    #[error("Counter-stake window has closed")]
+    CounterStakeWindowClosed = 48,
+
+    #[error("Task has not passed its deadline")]
+    TaskNotExpired = 49,
+
+    #[error("Deadline must be in the future and can only be extended once work has started")]
+    InvalidDeadline = 50,
+
+    #[error("Task still has contributions to withdraw")]
+    ContributionsOutstanding = 51,
+
+    #[error("Claimer is not on the task's allowlist")]
+    NotAllowedClaimer = 52,
+
+    #[error("Claimer does not meet the task's reputation requirements")]
+    InsufficientReputation = 53,
+
+    #[error("Invalid tags")]
+    InvalidTags = 54,
+
+    #[error("Category is not registered or not active")]
+    InvalidCategory = 55,
+
+    #[error("Invalid template")]
+    InvalidTemplate = 56,
+
+    #[error("Invalid vault schedule")]
+    InvalidSchedule = 57,
+
+    #[error("Scheduled task not due yet")]
+    ScheduleNotDue = 58,
+
+    #[error("Vault balance too low")]
+    InsufficientVault = 59,
+
+    #[error("Invalid task dependencies")]
+    InvalidDependencies = 60,
+
+    #[error("Prerequisite tasks not completed")]
+    DependenciesIncomplete = 61,
+
+    #[error("Template changed since it was read")]
+    TemplateChanged = 62,
+
+    #[error("Mint has an unsupported Token-2022 extension")]
+    UnsupportedMint = 63,
+
+    #[error("Creators cannot claim their own task")]
+    SelfClaim = 64,
+
+    #[error("Task is still a prerequisite of unclaimed tasks")]
+    HasDependents = 65,
+
+    #[error("Allowlist proof is malformed or deeper than MAX_CLAIMER_PROOF_DEPTH")]
+    InvalidProof = 66,
+
+    #[error("Task already rejected as many slot submissions as it has slots")]
+    SlotRejectionLimit = 67,
+
+    #[error("Contest can still be awarded by its creator")]
+    AwardWindowOpen = 68,
+
+    #[error("Contest award window has passed")]
+    AwardWindowClosed = 69,
 }
 
 error!(BountyBoardError);
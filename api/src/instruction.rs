@@ -1,4 +1,7 @@
 use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use crate::consts::{MAX_CLAIMER_PROOF_DEPTH, MAX_CONTEST_WINNERS, MAX_TASK_DEPENDENCIES};
 use num_enum::TryFromPrimitive;
 
 /// Instructions for the BountyBoard program
@@ -15,7 +18,7 @@ pub enum BountyBoardInstruction {
     SubmitWork = 3,
     /// Approve submitted work (releases escrow)
     ApproveWork = 4,
-    /// Reject submitted work (task returns to open)
+    /// Reject submitted work (task moves to rejected until the dispute window lapses)
     RejectWork = 5,
     /// Dispute a rejection
     Dispute = 6,
@@ -25,6 +28,101 @@ pub enum BountyBoardInstruction {
     CancelTask = 8,
     /// Auto-release expired escrow to worker (permissionless, anyone can call after timeout)
     ClaimExpired = 9,
+    /// Register an Agent reputation PDA for the signer
+    RegisterAgent = 10,
+    /// Return a rejected task to open once the dispute window lapses (permissionless)
+    ReopenTask = 11,
+    /// Declare a milestone tranche on an open task
+    AddMilestone = 12,
+    /// Submit proof for the next milestone
+    SubmitMilestone = 13,
+    /// Approve a submitted milestone (releases its tranche)
+    ApproveMilestone = 14,
+    /// Reject a submitted milestone (task moves to rejected)
+    RejectMilestone = 15,
+    /// Return a claimed task to open once its work window lapses (permissionless)
+    ReleaseStaleClaim = 16,
+    /// Admin updates protocol fee, dispute stake and default work window
+    UpdateConfig = 17,
+    /// Admin proposes a new admin (step 1 of 2)
+    ProposeAdmin = 18,
+    /// Proposed admin accepts the role (step 2 of 2)
+    AcceptAdmin = 19,
+    /// Admin withdraws collected fees from the treasury
+    WithdrawTreasury = 20,
+    /// Creator closes a completed or cancelled task and reclaims rent
+    CloseTask = 21,
+    /// Worker applies to an application-mode task
+    SubmitApplication = 22,
+    /// Creator picks an application and assigns the claimer
+    AcceptApplication = 23,
+    /// Close a pending application and return its rent and bond
+    CloseApplication = 24,
+    /// Agent submits an entry to a contest task
+    SubmitEntry = 25,
+    /// Creator awards ranked contest winners
+    AwardContest = 26,
+    /// Close a contest entry once the contest is over
+    CloseSubmission = 27,
+    /// Worker claims the next slot of a multi-slot task
+    ClaimSlot = 28,
+    /// Worker submits proof for their slot
+    SubmitSlot = 29,
+    /// Creator approves a slot → release its payout
+    ApproveSlot = 30,
+    /// Creator rejects a slot submission → slot frees up (at most `slots` times per task)
+    RejectSlot = 31,
+    /// Admin registers a dispute arbiter
+    AddArbiter = 32,
+    /// Admin removes a dispute arbiter
+    RemoveArbiter = 33,
+    /// Assigned arbiter votes on a dispute
+    CastVote = 34,
+    /// Settle a dispute once its panel reaches a decision (permissionless)
+    FinalizeDispute = 35,
+    /// Refund both sides of a dispute left unresolved past its timeout (permissionless)
+    ExpireDispute = 36,
+    /// Creator matches the dispute stake to contest the dispute
+    CounterStake = 37,
+    /// Creator or claimer attaches an evidence hash to the dispute
+    SubmitEvidence = 38,
+    /// Refund the creator of a task past its deadline (permissionless)
+    ExpireTask = 39,
+    /// Top up an open or claimed task's bounty (recorded per funder)
+    IncreaseBounty = 40,
+    /// Creator edits an open task's details or moves its deadline
+    UpdateTask = 41,
+    /// Funder reclaims their pro-rata share of a refunded bounty
+    WithdrawContribution = 42,
+    /// Admin registers a task category
+    AddCategory = 43,
+    /// Admin renames a task category or toggles whether new tasks may use it
+    UpdateCategory = 44,
+    /// Save a reusable task preset
+    CreateTemplate = 45,
+    /// Template owner edits the preset or hands it over
+    UpdateTemplate = 46,
+    /// Post and fund a task from a template
+    CreateTaskFromTemplate = 47,
+    /// Create a vault that posts the same task on a fixed interval
+    CreateVault = 48,
+    /// Fund a vault
+    DepositVault = 49,
+    /// Vault owner withdraws unspent funds
+    WithdrawVault = 50,
+    /// Vault owner stops the schedule and recovers the remaining balance
+    CloseVault = 51,
+    /// Post a vault's task once its interval elapses (permissionless)
+    SpawnScheduledTask = 52,
+    /// Free a claimed slot once its work window lapses (permissionless)
+    ReleaseStaleSlot = 53,
+    /// Pay out a submitted slot the creator never reviewed (permissionless)
+    ClaimExpiredSlot = 54,
+    /// Pay out a submitted milestone the creator never reviewed (permissionless)
+    ClaimExpiredMilestone = 55,
+    /// Pay an entrant its equal share of a contest left unawarded past the award
+    /// window (permissionless)
+    ClaimContestShare = 56,
 }
 
 // =============================================================================
@@ -71,6 +169,30 @@ pub struct CreateTaskArgs {
     pub deadline: [u8; 8],
     /// Compact tag encoding
     pub tags: [u8; 16],
+    /// Seconds the claimer has to submit (0 = Config default)
+    pub work_window: [u8; 8],
+    /// Lamports the claimer must stake at ClaimTask (0 = no bond)
+    pub claim_bond: [u8; 8],
+    /// Task flags (TASK_FLAG_*)
+    pub flags: u8,
+    /// Padding
+    pub _padding: [u8; 3],
+    /// Number of worker slots (0 or 1 = single worker); `bounty` is then per slot
+    pub slots: [u8; 4],
+    /// Only this wallet may claim (zero = anyone)
+    pub allowed_claimer: [u8; 32],
+    /// Merkle root of allowed claimers (zero = no allowlist)
+    pub claimer_root: [u8; 32],
+    /// Minimum completed tasks a claimer needs (0 = no requirement)
+    pub min_completions: [u8; 4],
+    /// Minimum claimer approval rate in basis points (0 = no requirement)
+    pub min_approval_bps: [u8; 2],
+    /// Number of prerequisite tasks in `dependencies`
+    pub dependency_count: u8,
+    /// Padding
+    pub _padding2: [u8; 1],
+    /// IDs of tasks that must be COMPLETED before this one can be claimed
+    pub dependencies: [[u8; 8]; MAX_TASK_DEPENDENCIES],
 }
 
 impl CreateTaskArgs {
@@ -80,7 +202,56 @@ impl CreateTaskArgs {
             description_hash,
             deadline: deadline.to_le_bytes(),
             tags,
+            work_window: [0u8; 8],
+            claim_bond: [0u8; 8],
+            flags: 0,
+            _padding: [0u8; 3],
+            slots: [0u8; 4],
+            allowed_claimer: [0u8; 32],
+            claimer_root: [0u8; 32],
+            min_completions: [0u8; 4],
+            min_approval_bps: [0u8; 2],
+            dependency_count: 0,
+            _padding2: [0u8; 1],
+            dependencies: [[0u8; 8]; MAX_TASK_DEPENDENCIES],
+        }
+    }
+    pub fn with_work_window(mut self, work_window: i64) -> Self {
+        self.work_window = work_window.to_le_bytes();
+        self
+    }
+    pub fn with_claim_bond(mut self, claim_bond: u64) -> Self {
+        self.claim_bond = claim_bond.to_le_bytes();
+        self
+    }
+    pub fn with_flags(mut self, flags: u8) -> Self {
+        self.flags = flags;
+        self
+    }
+    pub fn with_slots(mut self, slots: u32) -> Self {
+        self.slots = slots.to_le_bytes();
+        self
+    }
+    pub fn with_allowed_claimer(mut self, allowed_claimer: Pubkey) -> Self {
+        self.allowed_claimer = allowed_claimer.to_bytes();
+        self
+    }
+    pub fn with_claimer_root(mut self, claimer_root: [u8; 32]) -> Self {
+        self.claimer_root = claimer_root;
+        self
+    }
+    pub fn with_min_reputation(mut self, min_completions: u32, min_approval_bps: u16) -> Self {
+        self.min_completions = min_completions.to_le_bytes();
+        self.min_approval_bps = min_approval_bps.to_le_bytes();
+        self
+    }
+    pub fn with_dependencies(mut self, dependencies: &[u64]) -> Self {
+        let len = dependencies.len().min(MAX_TASK_DEPENDENCIES);
+        for (slot, id) in self.dependencies.iter_mut().zip(&dependencies[..len]) {
+            *slot = id.to_le_bytes();
         }
+        self.dependency_count = len as u8;
+        self
     }
     pub fn bounty(&self) -> u64 {
         u64::from_le_bytes(self.bounty)
@@ -88,9 +259,38 @@ impl CreateTaskArgs {
     pub fn deadline(&self) -> i64 {
         i64::from_le_bytes(self.deadline)
     }
+    pub fn work_window(&self) -> i64 {
+        i64::from_le_bytes(self.work_window)
+    }
+    pub fn claim_bond(&self) -> u64 {
+        u64::from_le_bytes(self.claim_bond)
+    }
+    pub fn slots(&self) -> u32 {
+        u32::from_le_bytes(self.slots)
+    }
+    pub fn allowed_claimer(&self) -> Pubkey {
+        Pubkey::new_from_array(self.allowed_claimer)
+    }
+    pub fn min_completions(&self) -> u32 {
+        u32::from_le_bytes(self.min_completions)
+    }
+    pub fn min_approval_bps(&self) -> u16 {
+        u16::from_le_bytes(self.min_approval_bps)
+    }
+    /// Prerequisite task IDs in use (the first `dependency_count`, capped at
+    /// MAX_TASK_DEPENDENCIES)
+    pub fn dependencies(&self) -> impl Iterator<Item = u64> + '_ {
+        self.dependencies
+            .iter()
+            .take(self.dependency_count as usize)
+            .map(|id| u64::from_le_bytes(*id))
+    }
 }
 
 /// Arguments for ClaimTask
+///
+/// Private tasks' allowlist merkle proof (sibling hashes, leaf first) follows the
+/// arguments as trailing instruction data, at most MAX_CLAIMER_PROOF_DEPTH hashes.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
 pub struct ClaimTaskArgs {
@@ -107,6 +307,32 @@ impl ClaimTaskArgs {
     pub fn task_id(&self) -> u64 {
         u64::from_le_bytes(self.task_id)
     }
+    /// Splits ClaimTask instruction data into its arguments and trailing proof.
+    /// None if the data is malformed or the proof is too deep.
+    ///
+    /// ```
+    /// use bountyboard_api::{consts::MAX_CLAIMER_PROOF_DEPTH, instruction::ClaimTaskArgs};
+    ///
+    /// let data = [bytemuck::bytes_of(&ClaimTaskArgs::new(7)), &[1u8; 64][..]].concat();
+    /// let (args, proof) = ClaimTaskArgs::parse(&data).unwrap();
+    /// assert_eq!((args.task_id(), proof), (7, &[[1u8; 32]; 2][..]));
+    ///
+    /// assert!(ClaimTaskArgs::parse(&data[..39]).is_none());
+    /// let too_deep = [&data[..8], &[0u8; 32 * (MAX_CLAIMER_PROOF_DEPTH + 1)][..]].concat();
+    /// assert!(ClaimTaskArgs::parse(&too_deep).is_none());
+    /// ```
+    pub fn parse(data: &[u8]) -> Option<(&Self, &[[u8; 32]])> {
+        if data.len() < std::mem::size_of::<Self>() {
+            return None;
+        }
+        let (args, proof) = data.split_at(std::mem::size_of::<Self>());
+        let args = bytemuck::try_from_bytes::<Self>(args).ok()?;
+        let proof = bytemuck::try_cast_slice::<u8, [u8; 32]>(proof).ok()?;
+        if proof.len() > MAX_CLAIMER_PROOF_DEPTH {
+            return None;
+        }
+        Some((args, proof))
+    }
 }
 
 /// Arguments for SubmitWork
@@ -194,23 +420,27 @@ impl DisputeArgs {
 pub struct ResolveDisputeArgs {
     /// Task ID
     pub task_id: [u8; 8],
-    /// Winner: 0 = creator wins (claimer loses stake), 1 = claimer wins (gets bounty + stake back)
-    pub winner: u8,
+    /// Claimer's share of the bounty in basis points
+    /// (0 = creator wins, 10000 = claimer wins)
+    pub claimer_share_bps: [u8; 2],
     /// Padding
-    pub _padding: [u8; 7],
+    pub _padding: [u8; 6],
 }
 
 impl ResolveDisputeArgs {
-    pub fn new(task_id: u64, winner: u8) -> Self {
+    pub fn new(task_id: u64, claimer_share_bps: u16) -> Self {
         Self {
             task_id: task_id.to_le_bytes(),
-            winner,
-            _padding: [0u8; 7],
+            claimer_share_bps: claimer_share_bps.to_le_bytes(),
+            _padding: [0u8; 6],
         }
     }
     pub fn task_id(&self) -> u64 {
         u64::from_le_bytes(self.task_id)
     }
+    pub fn claimer_share_bps(&self) -> u16 {
+        u16::from_le_bytes(self.claimer_share_bps)
+    }
 }
 
 /// Arguments for CancelTask
@@ -250,3 +480,1178 @@ impl ClaimExpiredArgs {
         u64::from_le_bytes(self.task_id)
     }
 }
+
+/// Arguments for ReopenTask
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ReopenTaskArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl ReopenTaskArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for AddMilestone
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct AddMilestoneArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Tranche amount (lamports or token base units)
+    pub amount: [u8; 8],
+    /// SHA256 hash of the milestone description
+    pub description_hash: [u8; 32],
+}
+
+impl AddMilestoneArgs {
+    pub fn new(task_id: u64, amount: u64, description_hash: [u8; 32]) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            amount: amount.to_le_bytes(),
+            description_hash,
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+    pub fn amount(&self) -> u64 {
+        u64::from_le_bytes(self.amount)
+    }
+}
+
+/// Arguments for SubmitMilestone
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct SubmitMilestoneArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Milestone index
+    pub index: u8,
+    /// Padding
+    pub _padding: [u8; 7],
+    /// SHA256 hash of the proof
+    pub proof_hash: [u8; 32],
+}
+
+impl SubmitMilestoneArgs {
+    pub fn new(task_id: u64, index: u8, proof_hash: [u8; 32]) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            index,
+            _padding: [0u8; 7],
+            proof_hash,
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for ApproveMilestone
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ApproveMilestoneArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Milestone index
+    pub index: u8,
+    /// Padding
+    pub _padding: [u8; 7],
+}
+
+impl ApproveMilestoneArgs {
+    pub fn new(task_id: u64, index: u8) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            index,
+            _padding: [0u8; 7],
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for RejectMilestone
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct RejectMilestoneArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Milestone index
+    pub index: u8,
+    /// Padding
+    pub _padding: [u8; 7],
+}
+
+impl RejectMilestoneArgs {
+    pub fn new(task_id: u64, index: u8) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            index,
+            _padding: [0u8; 7],
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for ReleaseStaleClaim
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ReleaseStaleClaimArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl ReleaseStaleClaimArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for UpdateConfig
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct UpdateConfigArgs {
+    /// Protocol fee in basis points (e.g. 200 = 2%)
+    pub protocol_fee_bps: [u8; 2],
+    /// Arbiters assigned to each dispute (0 = admin resolves)
+    pub arbiters_per_dispute: u8,
+    /// Padding
+    pub _padding: [u8; 5],
+    /// Dispute stake in lamports
+    pub dispute_stake: [u8; 8],
+    /// Default work window in seconds (0 = no limit)
+    pub default_work_window: [u8; 8],
+    /// Seconds after opening before an unresolved dispute can be expired
+    pub dispute_resolution_timeout: [u8; 8],
+}
+
+impl UpdateConfigArgs {
+    pub fn new(
+        protocol_fee_bps: u16,
+        dispute_stake: u64,
+        default_work_window: i64,
+        arbiters_per_dispute: u8,
+        dispute_resolution_timeout: i64,
+    ) -> Self {
+        Self {
+            protocol_fee_bps: protocol_fee_bps.to_le_bytes(),
+            arbiters_per_dispute,
+            _padding: [0u8; 5],
+            dispute_stake: dispute_stake.to_le_bytes(),
+            default_work_window: default_work_window.to_le_bytes(),
+            dispute_resolution_timeout: dispute_resolution_timeout.to_le_bytes(),
+        }
+    }
+    pub fn protocol_fee_bps(&self) -> u16 {
+        u16::from_le_bytes(self.protocol_fee_bps)
+    }
+    pub fn dispute_stake(&self) -> u64 {
+        u64::from_le_bytes(self.dispute_stake)
+    }
+    pub fn default_work_window(&self) -> i64 {
+        i64::from_le_bytes(self.default_work_window)
+    }
+    pub fn dispute_resolution_timeout(&self) -> i64 {
+        i64::from_le_bytes(self.dispute_resolution_timeout)
+    }
+}
+
+/// Arguments for ProposeAdmin
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ProposeAdminArgs {
+    /// Proposed admin wallet (Pubkey::default() cancels a pending proposal)
+    pub new_admin: [u8; 32],
+}
+
+impl ProposeAdminArgs {
+    pub fn new(new_admin: Pubkey) -> Self {
+        Self {
+            new_admin: new_admin.to_bytes(),
+        }
+    }
+    pub fn new_admin(&self) -> Pubkey {
+        Pubkey::new_from_array(self.new_admin)
+    }
+}
+
+/// Arguments for WithdrawTreasury
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct WithdrawTreasuryArgs {
+    /// Amount to withdraw (lamports, or token base units for token withdrawals)
+    pub amount: [u8; 8],
+}
+
+impl WithdrawTreasuryArgs {
+    pub fn new(amount: u64) -> Self {
+        Self {
+            amount: amount.to_le_bytes(),
+        }
+    }
+    pub fn amount(&self) -> u64 {
+        u64::from_le_bytes(self.amount)
+    }
+}
+
+/// Arguments for CloseTask
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct CloseTaskArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl CloseTaskArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for SubmitApplication
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct SubmitApplicationArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Price quote (must not exceed the bounty)
+    pub price: [u8; 8],
+    /// Estimated seconds to deliver
+    pub eta: [u8; 8],
+    /// SHA256 hash of the pitch
+    pub pitch_hash: [u8; 32],
+}
+
+impl SubmitApplicationArgs {
+    pub fn new(task_id: u64, price: u64, eta: i64, pitch_hash: [u8; 32]) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            price: price.to_le_bytes(),
+            eta: eta.to_le_bytes(),
+            pitch_hash,
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+    pub fn price(&self) -> u64 {
+        u64::from_le_bytes(self.price)
+    }
+    pub fn eta(&self) -> i64 {
+        i64::from_le_bytes(self.eta)
+    }
+}
+
+/// Arguments for AcceptApplication
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct AcceptApplicationArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl AcceptApplicationArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for CloseApplication
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct CloseApplicationArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl CloseApplicationArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for SubmitEntry
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct SubmitEntryArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// SHA256 hash of the entry
+    pub proof_hash: [u8; 32],
+}
+
+impl SubmitEntryArgs {
+    pub fn new(task_id: u64, proof_hash: [u8; 32]) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            proof_hash,
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for AwardContest
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct AwardContestArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Number of winners
+    pub winner_count: u8,
+    /// Padding
+    pub _padding: [u8; 7],
+    /// Share of the bounty per rank in basis points (must sum to TOTAL_BPS)
+    pub splits_bps: [[u8; 2]; MAX_CONTEST_WINNERS],
+}
+
+impl AwardContestArgs {
+    /// `splits_bps[i]` is the share of rank `i`; at most MAX_CONTEST_WINNERS ranks
+    pub fn new(task_id: u64, splits_bps: &[u16]) -> Self {
+        let mut splits = [[0u8; 2]; MAX_CONTEST_WINNERS];
+        for (split, bps) in splits.iter_mut().zip(splits_bps) {
+            *split = bps.to_le_bytes();
+        }
+        Self {
+            task_id: task_id.to_le_bytes(),
+            winner_count: splits_bps.len().min(MAX_CONTEST_WINNERS) as u8,
+            _padding: [0u8; 7],
+            splits_bps: splits,
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+    pub fn split_bps(&self, rank: usize) -> u16 {
+        u16::from_le_bytes(self.splits_bps[rank])
+    }
+}
+
+/// Arguments for CloseSubmission
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct CloseSubmissionArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl CloseSubmissionArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for ClaimSlot
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ClaimSlotArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl ClaimSlotArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for SubmitSlot
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct SubmitSlotArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Slot index
+    pub index: [u8; 4],
+    /// Padding
+    pub _padding: [u8; 4],
+    /// SHA256 hash of the proof
+    pub proof_hash: [u8; 32],
+}
+
+impl SubmitSlotArgs {
+    pub fn new(task_id: u64, index: u32, proof_hash: [u8; 32]) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            index: index.to_le_bytes(),
+            _padding: [0u8; 4],
+            proof_hash,
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+    pub fn index(&self) -> u32 {
+        u32::from_le_bytes(self.index)
+    }
+}
+
+/// Arguments for ApproveSlot
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ApproveSlotArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Slot index
+    pub index: [u8; 4],
+    /// Padding
+    pub _padding: [u8; 4],
+}
+
+impl ApproveSlotArgs {
+    pub fn new(task_id: u64, index: u32) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            index: index.to_le_bytes(),
+            _padding: [0u8; 4],
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+    pub fn index(&self) -> u32 {
+        u32::from_le_bytes(self.index)
+    }
+}
+
+/// Arguments for RejectSlot
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct RejectSlotArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Slot index
+    pub index: [u8; 4],
+    /// Padding
+    pub _padding: [u8; 4],
+}
+
+impl RejectSlotArgs {
+    pub fn new(task_id: u64, index: u32) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            index: index.to_le_bytes(),
+            _padding: [0u8; 4],
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+    pub fn index(&self) -> u32 {
+        u32::from_le_bytes(self.index)
+    }
+}
+
+/// Arguments for AddArbiter
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct AddArbiterArgs {
+    /// Arbiter wallet
+    pub wallet: [u8; 32],
+}
+
+impl AddArbiterArgs {
+    pub fn new(wallet: Pubkey) -> Self {
+        Self {
+            wallet: wallet.to_bytes(),
+        }
+    }
+    pub fn wallet(&self) -> Pubkey {
+        Pubkey::new_from_array(self.wallet)
+    }
+}
+
+/// Arguments for RemoveArbiter
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct RemoveArbiterArgs {
+    /// Index of the arbiter to remove
+    pub index: [u8; 4],
+}
+
+impl RemoveArbiterArgs {
+    pub fn new(index: u32) -> Self {
+        Self {
+            index: index.to_le_bytes(),
+        }
+    }
+    pub fn index(&self) -> u32 {
+        u32::from_le_bytes(self.index)
+    }
+}
+
+/// Arguments for CastVote
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct CastVoteArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Vote: VOTE_CREATOR or VOTE_CLAIMER
+    pub vote: u8,
+    /// Padding
+    pub _padding: [u8; 7],
+}
+
+impl CastVoteArgs {
+    pub fn new(task_id: u64, vote: u8) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            vote,
+            _padding: [0u8; 7],
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for FinalizeDispute
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct FinalizeDisputeArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl FinalizeDisputeArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for ExpireDispute
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ExpireDisputeArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl ExpireDisputeArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for CounterStake
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct CounterStakeArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl CounterStakeArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for SubmitEvidence
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct SubmitEvidenceArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Hash of the evidence (stored off-chain)
+    pub evidence_hash: [u8; 32],
+}
+
+impl SubmitEvidenceArgs {
+    pub fn new(task_id: u64, evidence_hash: [u8; 32]) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            evidence_hash,
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for ExpireTask
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ExpireTaskArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl ExpireTaskArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for IncreaseBounty
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct IncreaseBountyArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Amount to add (lamports, or token base units for token bounties)
+    pub amount: [u8; 8],
+}
+
+impl IncreaseBountyArgs {
+    pub fn new(task_id: u64, amount: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            amount: amount.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+    pub fn amount(&self) -> u64 {
+        u64::from_le_bytes(self.amount)
+    }
+}
+
+/// Arguments for UpdateTask
+///
+/// All fields replace the task's current values; pass the existing values for
+/// anything that should not change.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct UpdateTaskArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// SHA256 hash of the task description
+    pub description_hash: [u8; 32],
+    /// Deadline as unix timestamp (0 = no deadline)
+    pub deadline: [u8; 8],
+    /// Compact tag encoding
+    pub tags: [u8; 16],
+}
+
+impl UpdateTaskArgs {
+    pub fn new(task_id: u64, description_hash: [u8; 32], deadline: i64, tags: [u8; 16]) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            description_hash,
+            deadline: deadline.to_le_bytes(),
+            tags,
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+    pub fn deadline(&self) -> i64 {
+        i64::from_le_bytes(self.deadline)
+    }
+}
+
+/// Arguments for WithdrawContribution
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct WithdrawContributionArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl WithdrawContributionArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for AddCategory
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct AddCategoryArgs {
+    /// Category id (non-zero)
+    pub id: [u8; 2],
+    /// Padding
+    pub _padding: [u8; 6],
+    /// UTF-8 display name, zero-padded
+    pub name: [u8; 32],
+}
+
+impl AddCategoryArgs {
+    pub fn new(id: u16, name: [u8; 32]) -> Self {
+        Self {
+            id: id.to_le_bytes(),
+            _padding: [0u8; 6],
+            name,
+        }
+    }
+    pub fn id(&self) -> u16 {
+        u16::from_le_bytes(self.id)
+    }
+}
+
+/// Arguments for UpdateCategory
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct UpdateCategoryArgs {
+    /// Category id
+    pub id: [u8; 2],
+    /// 1 to accept new tasks, 0 to retire the category
+    pub active: u8,
+    /// Padding
+    pub _padding: [u8; 5],
+    /// UTF-8 display name, zero-padded
+    pub name: [u8; 32],
+}
+
+impl UpdateCategoryArgs {
+    pub fn new(id: u16, active: bool, name: [u8; 32]) -> Self {
+        Self {
+            id: id.to_le_bytes(),
+            active: active as u8,
+            _padding: [0u8; 5],
+            name,
+        }
+    }
+    pub fn id(&self) -> u16 {
+        u16::from_le_bytes(self.id)
+    }
+}
+
+/// Template settings, shared by CreateTemplate and UpdateTemplate
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct TemplateArgs {
+    /// Default bounty (lamports, or base units of `mint`)
+    pub bounty: [u8; 8],
+    /// Bounty mint (zero = native SOL)
+    pub mint: [u8; 32],
+    /// Seconds from instantiation to the task deadline (0 = no deadline)
+    pub deadline_offset: [u8; 8],
+    /// Seconds the claimer has to submit (0 = Config default)
+    pub work_window: [u8; 8],
+    /// Lamports the claimer must stake at ClaimTask (0 = no bond)
+    pub claim_bond: [u8; 8],
+    /// SHA256 hash of the task description
+    pub description_hash: [u8; 32],
+    /// Tags in the `crate::tags::Tags` layout
+    pub tags: [u8; 16],
+    /// Minimum completed tasks a claimer needs (0 = no requirement)
+    pub min_completions: [u8; 4],
+    /// Minimum claimer approval rate in basis points (0 = no requirement)
+    pub min_approval_bps: [u8; 2],
+    /// Task flags (TASK_FLAG_*)
+    pub flags: u8,
+    /// Padding
+    pub _padding: [u8; 1],
+}
+
+impl TemplateArgs {
+    pub fn new(bounty: u64, description_hash: [u8; 32], deadline_offset: i64, tags: [u8; 16]) -> Self {
+        Self {
+            bounty: bounty.to_le_bytes(),
+            mint: [0u8; 32],
+            deadline_offset: deadline_offset.to_le_bytes(),
+            work_window: [0u8; 8],
+            claim_bond: [0u8; 8],
+            description_hash,
+            tags,
+            min_completions: [0u8; 4],
+            min_approval_bps: [0u8; 2],
+            flags: 0,
+            _padding: [0u8; 1],
+        }
+    }
+    pub fn with_mint(mut self, mint: Pubkey) -> Self {
+        self.mint = mint.to_bytes();
+        self
+    }
+    pub fn with_work_window(mut self, work_window: i64) -> Self {
+        self.work_window = work_window.to_le_bytes();
+        self
+    }
+    pub fn with_claim_bond(mut self, claim_bond: u64) -> Self {
+        self.claim_bond = claim_bond.to_le_bytes();
+        self
+    }
+    pub fn with_flags(mut self, flags: u8) -> Self {
+        self.flags = flags;
+        self
+    }
+    pub fn with_min_reputation(mut self, min_completions: u32, min_approval_bps: u16) -> Self {
+        self.min_completions = min_completions.to_le_bytes();
+        self.min_approval_bps = min_approval_bps.to_le_bytes();
+        self
+    }
+    pub fn bounty(&self) -> u64 {
+        u64::from_le_bytes(self.bounty)
+    }
+    pub fn mint(&self) -> Pubkey {
+        Pubkey::new_from_array(self.mint)
+    }
+    pub fn deadline_offset(&self) -> i64 {
+        i64::from_le_bytes(self.deadline_offset)
+    }
+    pub fn work_window(&self) -> i64 {
+        i64::from_le_bytes(self.work_window)
+    }
+    pub fn claim_bond(&self) -> u64 {
+        u64::from_le_bytes(self.claim_bond)
+    }
+    pub fn min_completions(&self) -> u32 {
+        u32::from_le_bytes(self.min_completions)
+    }
+    pub fn min_approval_bps(&self) -> u16 {
+        u16::from_le_bytes(self.min_approval_bps)
+    }
+}
+
+/// Arguments for CreateTemplate
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct CreateTemplateArgs {
+    /// Template settings
+    pub template: TemplateArgs,
+}
+
+impl CreateTemplateArgs {
+    pub fn new(template: TemplateArgs) -> Self {
+        Self { template }
+    }
+}
+
+/// Arguments for UpdateTemplate
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct UpdateTemplateArgs {
+    /// Template ID
+    pub template_id: [u8; 8],
+    /// New owner (zero = keep the current owner)
+    pub new_owner: [u8; 32],
+    /// Template settings (replace the current ones)
+    pub template: TemplateArgs,
+}
+
+impl UpdateTemplateArgs {
+    pub fn new(template_id: u64, new_owner: Pubkey, template: TemplateArgs) -> Self {
+        Self {
+            template_id: template_id.to_le_bytes(),
+            new_owner: new_owner.to_bytes(),
+            template,
+        }
+    }
+    pub fn template_id(&self) -> u64 {
+        u64::from_le_bytes(self.template_id)
+    }
+    pub fn new_owner(&self) -> Pubkey {
+        Pubkey::new_from_array(self.new_owner)
+    }
+}
+
+/// Arguments for CreateTaskFromTemplate
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct CreateTaskFromTemplateArgs {
+    /// Template ID
+    pub template_id: [u8; 8],
+    /// `Template.updated_at` the caller read; the instruction fails if the
+    /// template has been updated since
+    pub expected_updated_at: [u8; 8],
+}
+
+impl CreateTaskFromTemplateArgs {
+    pub fn new(template_id: u64, expected_updated_at: i64) -> Self {
+        Self {
+            template_id: template_id.to_le_bytes(),
+            expected_updated_at: expected_updated_at.to_le_bytes(),
+        }
+    }
+    pub fn template_id(&self) -> u64 {
+        u64::from_le_bytes(self.template_id)
+    }
+    pub fn expected_updated_at(&self) -> i64 {
+        i64::from_le_bytes(self.expected_updated_at)
+    }
+}
+
+/// Arguments for CreateVault
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct CreateVaultArgs {
+    /// Seconds between spawns
+    pub interval: [u8; 8],
+    /// Unix timestamp of the first spawn (0 = now)
+    pub start_at: [u8; 8],
+    /// Initial deposit (lamports)
+    pub deposit: [u8; 8],
+    /// Settings of each spawned task
+    pub task: TemplateArgs,
+}
+
+impl CreateVaultArgs {
+    pub fn new(interval: i64, start_at: i64, deposit: u64, task: TemplateArgs) -> Self {
+        Self {
+            interval: interval.to_le_bytes(),
+            start_at: start_at.to_le_bytes(),
+            deposit: deposit.to_le_bytes(),
+            task,
+        }
+    }
+    pub fn interval(&self) -> i64 {
+        i64::from_le_bytes(self.interval)
+    }
+    pub fn start_at(&self) -> i64 {
+        i64::from_le_bytes(self.start_at)
+    }
+    pub fn deposit(&self) -> u64 {
+        u64::from_le_bytes(self.deposit)
+    }
+}
+
+/// Arguments for DepositVault
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct DepositVaultArgs {
+    /// Vault ID
+    pub vault_id: [u8; 8],
+    /// Amount (lamports)
+    pub amount: [u8; 8],
+}
+
+impl DepositVaultArgs {
+    pub fn new(vault_id: u64, amount: u64) -> Self {
+        Self {
+            vault_id: vault_id.to_le_bytes(),
+            amount: amount.to_le_bytes(),
+        }
+    }
+    pub fn vault_id(&self) -> u64 {
+        u64::from_le_bytes(self.vault_id)
+    }
+    pub fn amount(&self) -> u64 {
+        u64::from_le_bytes(self.amount)
+    }
+}
+
+/// Arguments for WithdrawVault
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct WithdrawVaultArgs {
+    /// Vault ID
+    pub vault_id: [u8; 8],
+    /// Amount (lamports)
+    pub amount: [u8; 8],
+}
+
+impl WithdrawVaultArgs {
+    pub fn new(vault_id: u64, amount: u64) -> Self {
+        Self {
+            vault_id: vault_id.to_le_bytes(),
+            amount: amount.to_le_bytes(),
+        }
+    }
+    pub fn vault_id(&self) -> u64 {
+        u64::from_le_bytes(self.vault_id)
+    }
+    pub fn amount(&self) -> u64 {
+        u64::from_le_bytes(self.amount)
+    }
+}
+
+/// Arguments for CloseVault
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct CloseVaultArgs {
+    /// Vault ID
+    pub vault_id: [u8; 8],
+}
+
+impl CloseVaultArgs {
+    pub fn new(vault_id: u64) -> Self {
+        Self {
+            vault_id: vault_id.to_le_bytes(),
+        }
+    }
+    pub fn vault_id(&self) -> u64 {
+        u64::from_le_bytes(self.vault_id)
+    }
+}
+
+/// Arguments for SpawnScheduledTask
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct SpawnScheduledTaskArgs {
+    /// Vault ID
+    pub vault_id: [u8; 8],
+}
+
+impl SpawnScheduledTaskArgs {
+    pub fn new(vault_id: u64) -> Self {
+        Self {
+            vault_id: vault_id.to_le_bytes(),
+        }
+    }
+    pub fn vault_id(&self) -> u64 {
+        u64::from_le_bytes(self.vault_id)
+    }
+}
+
+/// Arguments for ReleaseStaleSlot
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ReleaseStaleSlotArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Slot index
+    pub index: [u8; 4],
+    /// Padding
+    pub _padding: [u8; 4],
+}
+
+impl ReleaseStaleSlotArgs {
+    pub fn new(task_id: u64, index: u32) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            index: index.to_le_bytes(),
+            _padding: [0u8; 4],
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+    pub fn index(&self) -> u32 {
+        u32::from_le_bytes(self.index)
+    }
+}
+
+/// Arguments for ClaimExpiredSlot
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ClaimExpiredSlotArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Slot index
+    pub index: [u8; 4],
+    /// Padding
+    pub _padding: [u8; 4],
+}
+
+impl ClaimExpiredSlotArgs {
+    pub fn new(task_id: u64, index: u32) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            index: index.to_le_bytes(),
+            _padding: [0u8; 4],
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+    pub fn index(&self) -> u32 {
+        u32::from_le_bytes(self.index)
+    }
+}
+
+/// Arguments for ClaimExpiredMilestone
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ClaimExpiredMilestoneArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+    /// Milestone index
+    pub index: u8,
+    /// Padding
+    pub _padding: [u8; 7],
+}
+
+impl ClaimExpiredMilestoneArgs {
+    pub fn new(task_id: u64, index: u8) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+            index,
+            _padding: [0u8; 7],
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
+
+/// Arguments for ClaimContestShare
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ClaimContestShareArgs {
+    /// Task ID
+    pub task_id: [u8; 8],
+}
+
+impl ClaimContestShareArgs {
+    pub fn new(task_id: u64) -> Self {
+        Self {
+            task_id: task_id.to_le_bytes(),
+        }
+    }
+    pub fn task_id(&self) -> u64 {
+        u64::from_le_bytes(self.task_id)
+    }
+}
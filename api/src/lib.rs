@@ -3,12 +3,14 @@ pub mod error;
 pub mod instruction;
 pub mod sdk;
 pub mod state;
+pub mod tags;
 
 pub use consts::*;
 pub use error::*;
 pub use instruction::*;
 pub use sdk::*;
 pub use state::*;
+pub use tags::*;
 
 use steel::*;
 
@@ -3,11 +3,44 @@
 use solana_program::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
-    system_program,
 };
+use solana_sdk_ids::system_program;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 
-use crate::consts::{config_pda, task_pda, treasury_pda};
+use crate::consts::{
+    agent_pda, application_pda, arbiter_pda, category_pda, config_pda, contribution_pda, dispute_pda, milestone_pda, slot_pda, submission_pda, task_escrow_address, task_pda, template_pda, treasury_pda, treasury_token_address, vault_pda, MAX_CLAIMER_PROOF_DEPTH, TOTAL_BPS,
+};
+use crate::error::BountyBoardError;
 use crate::instruction::*;
+use crate::state::Template;
+use crate::tags::Tags;
+
+/// Mint and token program of an SPL Token / Token-2022 bounty
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TokenMint {
+    /// Bounty mint
+    pub mint: Pubkey,
+    /// Token program owning the mint (SPL Token or Token-2022)
+    pub token_program: Pubkey,
+}
+
+/// Trailing accounts for token bounties: mint, task escrow, token programs,
+/// then the associated token account of each wallet in `owners`.
+fn token_accounts(task_id: u64, token: TokenMint, owners: &[Pubkey]) -> Vec<AccountMeta> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(token.mint, false),
+        AccountMeta::new(task_escrow_address(task_id, &token.mint, &token.token_program), false),
+        AccountMeta::new_readonly(token.token_program, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+    ];
+    accounts.extend(owners.iter().map(|owner| {
+        AccountMeta::new(
+            get_associated_token_address_with_program_id(owner, &token.mint, &token.token_program),
+            false,
+        )
+    }));
+    accounts
+}
 
 /// Build an Initialize instruction
 pub fn initialize(admin: Pubkey, protocol_fee_bps: u16, dispute_stake: u64) -> Instruction {
@@ -31,14 +64,9 @@ pub fn initialize(admin: Pubkey, protocol_fee_bps: u16, dispute_stake: u64) -> I
 }
 
 /// Build a CreateTask instruction
-pub fn create_task(
-    creator: Pubkey,
-    task_id: u64,
-    bounty: u64,
-    description_hash: [u8; 32],
-    deadline: i64,
-    tags: [u8; 16],
-) -> Instruction {
+///
+/// Optional task settings are set on `args` (see `CreateTaskArgs::with_*`).
+pub fn create_task(creator: Pubkey, task_id: u64, args: CreateTaskArgs) -> Instruction {
     let config_address = config_pda().0;
     let task_address = task_pda(task_id).0;
 
@@ -49,15 +77,24 @@ pub fn create_task(
             AccountMeta::new(config_address, false),
             AccountMeta::new(task_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(agent_pda(&creator).0, false),
+            AccountMeta::new_readonly(category_pda(Tags::decode(&args.tags).category).0, false),
         ],
         data: [
             vec![BountyBoardInstruction::CreateTask as u8],
-            bytemuck::bytes_of(&CreateTaskArgs::new(bounty, description_hash, deadline, tags)).to_vec(),
+            bytemuck::bytes_of(&args).to_vec(),
         ]
         .concat(),
     }
 }
 
+/// Build a CreateTask instruction for a token bounty, funded from the creator's ATA
+pub fn create_task_token(creator: Pubkey, task_id: u64, args: CreateTaskArgs, token: TokenMint) -> Instruction {
+    let mut ix = create_task(creator, task_id, args);
+    ix.accounts.extend(token_accounts(task_id, token, &[creator]));
+    ix
+}
+
 /// Build a ClaimTask instruction
 pub fn claim_task(claimer: Pubkey, task_id: u64) -> Instruction {
     let task_address = task_pda(task_id).0;
@@ -67,6 +104,8 @@ pub fn claim_task(claimer: Pubkey, task_id: u64) -> Instruction {
         accounts: vec![
             AccountMeta::new(claimer, true),
             AccountMeta::new(task_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(agent_pda(&claimer).0, false),
         ],
         data: [
             vec![BountyBoardInstruction::ClaimTask as u8],
@@ -76,6 +115,41 @@ pub fn claim_task(claimer: Pubkey, task_id: u64) -> Instruction {
     }
 }
 
+/// Build a ClaimTask instruction for a private task, proving the claimer is on
+/// its allowlist (pass an empty proof for a direct hire). Fails with
+/// `InvalidProof` if the proof is deeper than MAX_CLAIMER_PROOF_DEPTH.
+pub fn claim_task_with_proof(
+    claimer: Pubkey,
+    task_id: u64,
+    proof: &[[u8; 32]],
+) -> Result<Instruction, BountyBoardError> {
+    if proof.len() > MAX_CLAIMER_PROOF_DEPTH {
+        return Err(BountyBoardError::InvalidProof);
+    }
+    let mut ix = claim_task(claimer, task_id);
+    ix.data.extend(proof.iter().flatten());
+    Ok(ix)
+}
+
+/// Build a ClaimTask instruction for a task with prerequisites, passing the
+/// prerequisite Task PDAs (`dependencies` in the task's order)
+pub fn claim_task_with_dependencies(claimer: Pubkey, task_id: u64, dependencies: &[u64]) -> Instruction {
+    with_dependencies(claim_task(claimer, task_id), dependencies)
+}
+
+/// Appends a dependent task's prerequisite Task PDAs (`dependencies` in the task's
+/// order) to a CreateTask instruction, or to a ClaimTask, CancelTask or ExpireTask
+/// instruction for a task that has not been claimed yet. Goes after any token
+/// accounts.
+pub fn with_dependencies(mut ix: Instruction, dependencies: &[u64]) -> Instruction {
+    ix.accounts.extend(
+        dependencies
+            .iter()
+            .map(|id| AccountMeta::new(task_pda(*id).0, false)),
+    );
+    ix
+}
+
 /// Build a SubmitWork instruction
 pub fn submit_work(claimer: Pubkey, task_id: u64, proof_hash: [u8; 32]) -> Instruction {
     let task_address = task_pda(task_id).0;
@@ -109,6 +183,8 @@ pub fn approve_work(creator: Pubkey, task_id: u64, claimer: Pubkey) -> Instructi
             AccountMeta::new(claimer, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(agent_pda(&creator).0, false),
+            AccountMeta::new(agent_pda(&claimer).0, false),
         ],
         data: [
             vec![BountyBoardInstruction::ApproveWork as u8],
@@ -118,8 +194,15 @@ pub fn approve_work(creator: Pubkey, task_id: u64, claimer: Pubkey) -> Instructi
     }
 }
 
+/// Build an ApproveWork instruction for a token bounty
+pub fn approve_work_token(creator: Pubkey, task_id: u64, claimer: Pubkey, token: TokenMint) -> Instruction {
+    let mut ix = approve_work(creator, task_id, claimer);
+    ix.accounts.extend(token_accounts(task_id, token, &[claimer, treasury_pda().0]));
+    ix
+}
+
 /// Build a RejectWork instruction
-pub fn reject_work(creator: Pubkey, task_id: u64) -> Instruction {
+pub fn reject_work(creator: Pubkey, task_id: u64, claimer: Pubkey) -> Instruction {
     let task_address = task_pda(task_id).0;
 
     Instruction {
@@ -127,6 +210,7 @@ pub fn reject_work(creator: Pubkey, task_id: u64) -> Instruction {
         accounts: vec![
             AccountMeta::new(creator, true),
             AccountMeta::new(task_address, false),
+            AccountMeta::new(agent_pda(&claimer).0, false),
         ],
         data: [
             vec![BountyBoardInstruction::RejectWork as u8],
@@ -137,18 +221,28 @@ pub fn reject_work(creator: Pubkey, task_id: u64) -> Instruction {
 }
 
 /// Build a Dispute instruction
-pub fn dispute(claimer: Pubkey, task_id: u64) -> Instruction {
+///
+/// `panel` is the arbiter registry indices returned by `Config::next_panel`.
+pub fn dispute(claimer: Pubkey, task_id: u64, panel: &[u32]) -> Instruction {
     let config_address = config_pda().0;
     let task_address = task_pda(task_id).0;
 
+    let mut accounts = vec![
+        AccountMeta::new(claimer, true),
+        AccountMeta::new(config_address, false),
+        AccountMeta::new(task_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(dispute_pda(task_id).0, false),
+    ];
+    accounts.extend(
+        panel
+            .iter()
+            .map(|index| AccountMeta::new_readonly(arbiter_pda(*index).0, false)),
+    );
+
     Instruction {
         program_id: crate::ID,
-        accounts: vec![
-            AccountMeta::new(claimer, true),
-            AccountMeta::new(config_address, false),
-            AccountMeta::new(task_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-        ],
+        accounts,
         data: [
             vec![BountyBoardInstruction::Dispute as u8],
             bytemuck::bytes_of(&DisputeArgs::new(task_id)).to_vec(),
@@ -158,7 +252,16 @@ pub fn dispute(claimer: Pubkey, task_id: u64) -> Instruction {
 }
 
 /// Build a ResolveDispute instruction
-pub fn resolve_dispute(admin: Pubkey, task_id: u64, winner: u8, claimer: Pubkey, creator: Pubkey) -> Instruction {
+///
+/// `claimer_share_bps` is the claimer's share of the bounty
+/// (0 = creator wins, 10000 = claimer wins).
+pub fn resolve_dispute(
+    admin: Pubkey,
+    task_id: u64,
+    claimer_share_bps: u16,
+    claimer: Pubkey,
+    creator: Pubkey,
+) -> Instruction {
     let config_address = config_pda().0;
     let task_address = task_pda(task_id).0;
     let treasury_address = treasury_pda().0;
@@ -169,21 +272,45 @@ pub fn resolve_dispute(admin: Pubkey, task_id: u64, winner: u8, claimer: Pubkey,
             AccountMeta::new(admin, true),
             AccountMeta::new(config_address, false),
             AccountMeta::new(task_address, false),
+            AccountMeta::new(dispute_pda(task_id).0, false),
             AccountMeta::new(claimer, false),
             AccountMeta::new(creator, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(agent_pda(&creator).0, false),
+            AccountMeta::new(agent_pda(&claimer).0, false),
         ],
         data: [
             vec![BountyBoardInstruction::ResolveDispute as u8],
-            bytemuck::bytes_of(&ResolveDisputeArgs::new(task_id, winner)).to_vec(),
+            bytemuck::bytes_of(&ResolveDisputeArgs::new(task_id, claimer_share_bps)).to_vec(),
         ]
         .concat(),
     }
 }
 
+/// Build a ResolveDispute instruction for a token bounty
+pub fn resolve_dispute_token(
+    admin: Pubkey,
+    task_id: u64,
+    claimer_share_bps: u16,
+    claimer: Pubkey,
+    creator: Pubkey,
+    token: TokenMint,
+) -> Instruction {
+    let mut ix = resolve_dispute(admin, task_id, claimer_share_bps, claimer, creator);
+    let mut owners = Vec::new();
+    if claimer_share_bps > 0 {
+        owners.extend([claimer, treasury_pda().0]);
+    }
+    if claimer_share_bps < TOTAL_BPS as u16 {
+        owners.push(creator);
+    }
+    ix.accounts.extend(token_accounts(task_id, token, &owners));
+    ix
+}
+
 /// Build a ClaimExpired instruction (permissionless auto-release)
-pub fn claim_expired(caller: Pubkey, task_id: u64, claimer: Pubkey) -> Instruction {
+pub fn claim_expired(caller: Pubkey, task_id: u64, creator: Pubkey, claimer: Pubkey) -> Instruction {
     let config_address = config_pda().0;
     let task_address = task_pda(task_id).0;
     let treasury_address = treasury_pda().0;
@@ -197,6 +324,8 @@ pub fn claim_expired(caller: Pubkey, task_id: u64, claimer: Pubkey) -> Instructi
             AccountMeta::new(claimer, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(agent_pda(&creator).0, false),
+            AccountMeta::new(agent_pda(&claimer).0, false),
         ],
         data: [
             vec![BountyBoardInstruction::ClaimExpired as u8],
@@ -206,6 +335,19 @@ pub fn claim_expired(caller: Pubkey, task_id: u64, claimer: Pubkey) -> Instructi
     }
 }
 
+/// Build a ClaimExpired instruction for a token bounty
+pub fn claim_expired_token(
+    caller: Pubkey,
+    task_id: u64,
+    creator: Pubkey,
+    claimer: Pubkey,
+    token: TokenMint,
+) -> Instruction {
+    let mut ix = claim_expired(caller, task_id, creator, claimer);
+    ix.accounts.extend(token_accounts(task_id, token, &[claimer, treasury_pda().0]));
+    ix
+}
+
 /// Build a CancelTask instruction
 pub fn cancel_task(creator: Pubkey, task_id: u64) -> Instruction {
     let config_address = config_pda().0;
@@ -226,3 +368,1254 @@ pub fn cancel_task(creator: Pubkey, task_id: u64) -> Instruction {
         .concat(),
     }
 }
+
+/// Build a CancelTask instruction for a token bounty
+pub fn cancel_task_token(creator: Pubkey, task_id: u64, token: TokenMint) -> Instruction {
+    let mut ix = cancel_task(creator, task_id);
+    ix.accounts.extend(token_accounts(task_id, token, &[creator]));
+    ix
+}
+
+/// Build a RegisterAgent instruction
+pub fn register_agent(wallet: Pubkey) -> Instruction {
+    let agent_address = agent_pda(&wallet).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(wallet, true),
+            AccountMeta::new(agent_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: vec![BountyBoardInstruction::RegisterAgent as u8],
+    }
+}
+
+/// Build a ReopenTask instruction (permissionless, after the dispute window)
+pub fn reopen_task(caller: Pubkey, task_id: u64, claimer: Pubkey) -> Instruction {
+    let task_address = task_pda(task_id).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(caller, true),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(claimer, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::ReopenTask as u8],
+            bytemuck::bytes_of(&ReopenTaskArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an AddMilestone instruction (`index` is the task's current milestone count)
+pub fn add_milestone(
+    creator: Pubkey,
+    task_id: u64,
+    index: u8,
+    amount: u64,
+    description_hash: [u8; 32],
+) -> Instruction {
+    let task_address = task_pda(task_id).0;
+    let milestone_address = milestone_pda(task_id, index).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(creator, true),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(milestone_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::AddMilestone as u8],
+            bytemuck::bytes_of(&AddMilestoneArgs::new(task_id, amount, description_hash)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a SubmitMilestone instruction
+pub fn submit_milestone(claimer: Pubkey, task_id: u64, index: u8, proof_hash: [u8; 32]) -> Instruction {
+    let task_address = task_pda(task_id).0;
+    let milestone_address = milestone_pda(task_id, index).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(claimer, true),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(milestone_address, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::SubmitMilestone as u8],
+            bytemuck::bytes_of(&SubmitMilestoneArgs::new(task_id, index, proof_hash)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an ApproveMilestone instruction
+pub fn approve_milestone(creator: Pubkey, task_id: u64, index: u8, claimer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+    let milestone_address = milestone_pda(task_id, index).0;
+    let treasury_address = treasury_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(creator, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(milestone_address, false),
+            AccountMeta::new(claimer, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(agent_pda(&creator).0, false),
+            AccountMeta::new(agent_pda(&claimer).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::ApproveMilestone as u8],
+            bytemuck::bytes_of(&ApproveMilestoneArgs::new(task_id, index)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an ApproveMilestone instruction for a token bounty
+pub fn approve_milestone_token(
+    creator: Pubkey,
+    task_id: u64,
+    index: u8,
+    claimer: Pubkey,
+    token: TokenMint,
+) -> Instruction {
+    let mut ix = approve_milestone(creator, task_id, index, claimer);
+    ix.accounts.extend(token_accounts(task_id, token, &[claimer, treasury_pda().0]));
+    ix
+}
+
+/// Build a RejectMilestone instruction
+pub fn reject_milestone(creator: Pubkey, task_id: u64, index: u8, claimer: Pubkey) -> Instruction {
+    let task_address = task_pda(task_id).0;
+    let milestone_address = milestone_pda(task_id, index).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(creator, true),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(milestone_address, false),
+            AccountMeta::new(agent_pda(&claimer).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::RejectMilestone as u8],
+            bytemuck::bytes_of(&RejectMilestoneArgs::new(task_id, index)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a ReleaseStaleClaim instruction (permissionless, after the work window)
+pub fn release_stale_claim(caller: Pubkey, task_id: u64, creator: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(caller, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(creator, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::ReleaseStaleClaim as u8],
+            bytemuck::bytes_of(&ReleaseStaleClaimArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an UpdateConfig instruction (admin only)
+pub fn update_config(
+    admin: Pubkey,
+    protocol_fee_bps: u16,
+    dispute_stake: u64,
+    default_work_window: i64,
+    arbiters_per_dispute: u8,
+    dispute_resolution_timeout: i64,
+) -> Instruction {
+    let config_address = config_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::UpdateConfig as u8],
+            bytemuck::bytes_of(&UpdateConfigArgs::new(
+                protocol_fee_bps,
+                dispute_stake,
+                default_work_window,
+                arbiters_per_dispute,
+                dispute_resolution_timeout,
+            ))
+            .to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a ProposeAdmin instruction (admin only)
+pub fn propose_admin(admin: Pubkey, new_admin: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::ProposeAdmin as u8],
+            bytemuck::bytes_of(&ProposeAdminArgs::new(new_admin)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an AcceptAdmin instruction (signed by the proposed admin)
+pub fn accept_admin(new_admin: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(new_admin, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: vec![BountyBoardInstruction::AcceptAdmin as u8],
+    }
+}
+
+/// Build a WithdrawTreasury instruction for native SOL fees (admin only)
+pub fn withdraw_treasury(admin: Pubkey, destination: Pubkey, amount: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let treasury_address = treasury_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(destination, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::WithdrawTreasury as u8],
+            bytemuck::bytes_of(&WithdrawTreasuryArgs::new(amount)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a WithdrawTreasury instruction for token fees (admin only).
+/// `destination` is a token account of `token.mint`.
+pub fn withdraw_treasury_token(admin: Pubkey, destination: Pubkey, amount: u64, token: TokenMint) -> Instruction {
+    let mut ix = withdraw_treasury(admin, destination, amount);
+    ix.accounts.extend([
+        AccountMeta::new_readonly(token.mint, false),
+        AccountMeta::new(treasury_token_address(&token.mint, &token.token_program), false),
+        AccountMeta::new_readonly(token.token_program, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+    ]);
+    ix
+}
+
+/// Build a CloseTask instruction. `milestone_count` must match the task's milestones
+/// and `funders` list the open contributions to settle in this call; the task is
+/// closed once none are left, so many funders can be settled over several calls.
+pub fn close_task(creator: Pubkey, task_id: u64, milestone_count: u8, funders: &[Pubkey]) -> Instruction {
+    let task_address = task_pda(task_id).0;
+
+    let mut accounts = vec![
+        AccountMeta::new(creator, true),
+        AccountMeta::new(task_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    accounts.extend((0..milestone_count).map(|index| AccountMeta::new(milestone_pda(task_id, index).0, false)));
+    for funder in funders {
+        accounts.push(AccountMeta::new(contribution_pda(task_id, funder).0, false));
+        accounts.push(AccountMeta::new(*funder, false));
+    }
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: [
+            vec![BountyBoardInstruction::CloseTask as u8],
+            bytemuck::bytes_of(&CloseTaskArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a CloseTask instruction for a token bounty (also closes the escrow
+/// token account)
+pub fn close_task_token(
+    creator: Pubkey,
+    task_id: u64,
+    milestone_count: u8,
+    funders: &[Pubkey],
+    token: TokenMint,
+) -> Instruction {
+    let mut ix = close_task(creator, task_id, milestone_count, funders);
+    let rest = ix.accounts.split_off(3);
+    let owners: Vec<Pubkey> = std::iter::once(creator).chain(funders.iter().copied()).collect();
+    ix.accounts.extend(token_accounts(task_id, token, &owners));
+    ix.accounts.extend(rest);
+    ix
+}
+
+/// Build a SubmitApplication instruction for an application-mode task
+pub fn submit_application(
+    applicant: Pubkey,
+    task_id: u64,
+    price: u64,
+    eta: i64,
+    pitch_hash: [u8; 32],
+) -> Instruction {
+    let task_address = task_pda(task_id).0;
+    let application_address = application_pda(task_id, &applicant).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(applicant, true),
+            AccountMeta::new_readonly(task_address, false),
+            AccountMeta::new(application_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::SubmitApplication as u8],
+            bytemuck::bytes_of(&SubmitApplicationArgs::new(task_id, price, eta, pitch_hash)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an AcceptApplication instruction (creator only)
+pub fn accept_application(creator: Pubkey, task_id: u64, applicant: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+    let application_address = application_pda(task_id, &applicant).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(creator, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(application_address, false),
+            AccountMeta::new(applicant, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::AcceptApplication as u8],
+            bytemuck::bytes_of(&AcceptApplicationArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an AcceptApplication instruction for a token bounty
+pub fn accept_application_token(creator: Pubkey, task_id: u64, applicant: Pubkey, token: TokenMint) -> Instruction {
+    let mut ix = accept_application(creator, task_id, applicant);
+    ix.accounts.extend(token_accounts(task_id, token, &[creator]));
+    ix
+}
+
+/// Build a CloseApplication instruction
+pub fn close_application(caller: Pubkey, task_id: u64, applicant: Pubkey) -> Instruction {
+    let task_address = task_pda(task_id).0;
+    let application_address = application_pda(task_id, &applicant).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(caller, true),
+            AccountMeta::new_readonly(task_address, false),
+            AccountMeta::new(application_address, false),
+            AccountMeta::new(applicant, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::CloseApplication as u8],
+            bytemuck::bytes_of(&CloseApplicationArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a SubmitEntry instruction for a contest task
+pub fn submit_entry(entrant: Pubkey, task_id: u64, proof_hash: [u8; 32]) -> Instruction {
+    let task_address = task_pda(task_id).0;
+    let submission_address = submission_pda(task_id, &entrant).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(entrant, true),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(submission_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::SubmitEntry as u8],
+            bytemuck::bytes_of(&SubmitEntryArgs::new(task_id, proof_hash)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an AwardContest instruction (creator only).
+/// `winners` are entrant wallets in rank order, `splits_bps` their shares.
+pub fn award_contest(creator: Pubkey, task_id: u64, winners: &[Pubkey], splits_bps: &[u16]) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+    let treasury_address = treasury_pda().0;
+
+    let mut accounts = vec![
+        AccountMeta::new(creator, true),
+        AccountMeta::new(config_address, false),
+        AccountMeta::new(task_address, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    for winner in winners {
+        accounts.push(AccountMeta::new(submission_pda(task_id, winner).0, false));
+        accounts.push(AccountMeta::new(*winner, false));
+    }
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: [
+            vec![BountyBoardInstruction::AwardContest as u8],
+            bytemuck::bytes_of(&AwardContestArgs::new(task_id, splits_bps)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an AwardContest instruction for a token bounty
+pub fn award_contest_token(
+    creator: Pubkey,
+    task_id: u64,
+    winners: &[Pubkey],
+    splits_bps: &[u16],
+    token: TokenMint,
+) -> Instruction {
+    let mut ix = award_contest(creator, task_id, winners, splits_bps);
+    let recipients = [&[treasury_pda().0], winners].concat();
+    ix.accounts.extend(token_accounts(task_id, token, &recipients));
+    ix
+}
+
+/// Build a CloseSubmission instruction (permissionless, once the contest is over)
+pub fn close_submission(caller: Pubkey, task_id: u64, entrant: Pubkey) -> Instruction {
+    let task_address = task_pda(task_id).0;
+    let submission_address = submission_pda(task_id, &entrant).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(caller, true),
+            AccountMeta::new_readonly(task_address, false),
+            AccountMeta::new(submission_address, false),
+            AccountMeta::new(entrant, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::CloseSubmission as u8],
+            bytemuck::bytes_of(&CloseSubmissionArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a ClaimSlot instruction. `slot_index` is the task's current `next_slot`.
+pub fn claim_slot(claimer: Pubkey, task_id: u64, slot_index: u32) -> Instruction {
+    let task_address = task_pda(task_id).0;
+    let slot_address = slot_pda(task_id, slot_index).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(claimer, true),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(slot_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::ClaimSlot as u8],
+            bytemuck::bytes_of(&ClaimSlotArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a SubmitSlot instruction
+pub fn submit_slot(claimer: Pubkey, task_id: u64, slot_index: u32, proof_hash: [u8; 32]) -> Instruction {
+    let task_address = task_pda(task_id).0;
+    let slot_address = slot_pda(task_id, slot_index).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(claimer, true),
+            AccountMeta::new_readonly(task_address, false),
+            AccountMeta::new(slot_address, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::SubmitSlot as u8],
+            bytemuck::bytes_of(&SubmitSlotArgs::new(task_id, slot_index, proof_hash)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an ApproveSlot instruction
+pub fn approve_slot(creator: Pubkey, task_id: u64, slot_index: u32, claimer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+    let slot_address = slot_pda(task_id, slot_index).0;
+    let treasury_address = treasury_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(creator, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(slot_address, false),
+            AccountMeta::new(claimer, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(agent_pda(&creator).0, false),
+            AccountMeta::new(agent_pda(&claimer).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::ApproveSlot as u8],
+            bytemuck::bytes_of(&ApproveSlotArgs::new(task_id, slot_index)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an ApproveSlot instruction for a token bounty
+pub fn approve_slot_token(
+    creator: Pubkey,
+    task_id: u64,
+    slot_index: u32,
+    claimer: Pubkey,
+    token: TokenMint,
+) -> Instruction {
+    let mut ix = approve_slot(creator, task_id, slot_index, claimer);
+    ix.accounts.extend(token_accounts(task_id, token, &[claimer, treasury_pda().0]));
+    ix
+}
+
+/// Build a RejectSlot instruction
+pub fn reject_slot(creator: Pubkey, task_id: u64, slot_index: u32, claimer: Pubkey) -> Instruction {
+    let task_address = task_pda(task_id).0;
+    let slot_address = slot_pda(task_id, slot_index).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(creator, true),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(slot_address, false),
+            AccountMeta::new(claimer, false),
+            AccountMeta::new(agent_pda(&claimer).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::RejectSlot as u8],
+            bytemuck::bytes_of(&RejectSlotArgs::new(task_id, slot_index)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an AddArbiter instruction (admin only)
+///
+/// `index` is the current `Config::arbiter_count`.
+pub fn add_arbiter(admin: Pubkey, index: u32, wallet: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(arbiter_pda(index).0, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::AddArbiter as u8],
+            bytemuck::bytes_of(&AddArbiterArgs::new(wallet)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a RemoveArbiter instruction (admin only)
+///
+/// `last_index` is `Config::arbiter_count - 1`.
+pub fn remove_arbiter(admin: Pubkey, index: u32, last_index: u32) -> Instruction {
+    let config_address = config_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(arbiter_pda(index).0, false),
+            AccountMeta::new(arbiter_pda(last_index).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::RemoveArbiter as u8],
+            bytemuck::bytes_of(&RemoveArbiterArgs::new(index)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a CastVote instruction (panel arbiter only)
+pub fn cast_vote(arbiter: Pubkey, task_id: u64, vote: u8) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(arbiter, true),
+            AccountMeta::new(dispute_pda(task_id).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::CastVote as u8],
+            bytemuck::bytes_of(&CastVoteArgs::new(task_id, vote)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a FinalizeDispute instruction (permissionless)
+///
+/// `arbiters` is the dispute's panel, in the order stored on the Dispute PDA.
+pub fn finalize_dispute(
+    caller: Pubkey,
+    task_id: u64,
+    claimer: Pubkey,
+    creator: Pubkey,
+    arbiters: &[Pubkey],
+) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+    let treasury_address = treasury_pda().0;
+
+    let mut accounts = vec![
+        AccountMeta::new(caller, true),
+        AccountMeta::new(config_address, false),
+        AccountMeta::new(task_address, false),
+        AccountMeta::new(dispute_pda(task_id).0, false),
+        AccountMeta::new(claimer, false),
+        AccountMeta::new(creator, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(agent_pda(&creator).0, false),
+        AccountMeta::new(agent_pda(&claimer).0, false),
+    ];
+    accounts.extend(arbiters.iter().map(|arbiter| AccountMeta::new(*arbiter, false)));
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: [
+            vec![BountyBoardInstruction::FinalizeDispute as u8],
+            bytemuck::bytes_of(&FinalizeDisputeArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a FinalizeDispute instruction for a token bounty
+///
+/// `claimer_wins` must match the panel's decision so the right token accounts are passed.
+pub fn finalize_dispute_token(
+    caller: Pubkey,
+    task_id: u64,
+    claimer: Pubkey,
+    creator: Pubkey,
+    arbiters: &[Pubkey],
+    claimer_wins: bool,
+    token: TokenMint,
+) -> Instruction {
+    let mut ix = finalize_dispute(caller, task_id, claimer, creator, arbiters);
+    let owners = if claimer_wins {
+        vec![claimer, treasury_pda().0]
+    } else {
+        vec![creator]
+    };
+    ix.accounts.extend(token_accounts(task_id, token, &owners));
+    ix
+}
+
+/// Build an ExpireDispute instruction (permissionless, after the resolution timeout)
+pub fn expire_dispute(caller: Pubkey, task_id: u64, claimer: Pubkey, creator: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(caller, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(dispute_pda(task_id).0, false),
+            AccountMeta::new(claimer, false),
+            AccountMeta::new(creator, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::ExpireDispute as u8],
+            bytemuck::bytes_of(&ExpireDisputeArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an ExpireDispute instruction for a token bounty
+pub fn expire_dispute_token(
+    caller: Pubkey,
+    task_id: u64,
+    claimer: Pubkey,
+    creator: Pubkey,
+    token: TokenMint,
+) -> Instruction {
+    let mut ix = expire_dispute(caller, task_id, claimer, creator);
+    ix.accounts.extend(token_accounts(task_id, token, &[creator]));
+    ix
+}
+
+/// Build a CounterStake instruction (task creator only)
+pub fn counter_stake(creator: Pubkey, task_id: u64) -> Instruction {
+    let task_address = task_pda(task_id).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(creator, true),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(dispute_pda(task_id).0, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::CounterStake as u8],
+            bytemuck::bytes_of(&CounterStakeArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a SubmitEvidence instruction (creator or claimer)
+pub fn submit_evidence(party: Pubkey, task_id: u64, evidence_hash: [u8; 32]) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(party, true),
+            AccountMeta::new(dispute_pda(task_id).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::SubmitEvidence as u8],
+            bytemuck::bytes_of(&SubmitEvidenceArgs::new(task_id, evidence_hash)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an ExpireTask instruction (permissionless, after the task deadline)
+pub fn expire_task(caller: Pubkey, task_id: u64, creator: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(caller, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(creator, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::ExpireTask as u8],
+            bytemuck::bytes_of(&ExpireTaskArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an ExpireTask instruction for a token bounty
+pub fn expire_task_token(caller: Pubkey, task_id: u64, creator: Pubkey, token: TokenMint) -> Instruction {
+    let mut ix = expire_task(caller, task_id, creator);
+    ix.accounts.extend(token_accounts(task_id, token, &[creator]));
+    ix
+}
+
+/// Build an IncreaseBounty instruction (anyone can fund)
+pub fn increase_bounty(funder: Pubkey, task_id: u64, amount: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(contribution_pda(task_id, &funder).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::IncreaseBounty as u8],
+            bytemuck::bytes_of(&IncreaseBountyArgs::new(task_id, amount)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an IncreaseBounty instruction for a token bounty, funded from the
+/// funder's associated token account
+pub fn increase_bounty_token(funder: Pubkey, task_id: u64, amount: u64, token: TokenMint) -> Instruction {
+    let mut ix = increase_bounty(funder, task_id, amount);
+    ix.accounts.extend(token_accounts(task_id, token, &[funder]));
+    ix
+}
+
+/// Build an UpdateTask instruction (creator only)
+///
+/// Every field replaces the current value; pass the existing ones to keep them.
+pub fn update_task(
+    creator: Pubkey,
+    task_id: u64,
+    description_hash: [u8; 32],
+    deadline: i64,
+    tags: [u8; 16],
+) -> Instruction {
+    let task_address = task_pda(task_id).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(creator, true),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new_readonly(category_pda(Tags::decode(&tags).category).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::UpdateTask as u8],
+            bytemuck::bytes_of(&UpdateTaskArgs::new(task_id, description_hash, deadline, tags)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a WithdrawContribution instruction (funder only, once the task is finished)
+pub fn withdraw_contribution(funder: Pubkey, task_id: u64) -> Instruction {
+    let task_address = task_pda(task_id).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(contribution_pda(task_id, &funder).0, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::WithdrawContribution as u8],
+            bytemuck::bytes_of(&WithdrawContributionArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a WithdrawContribution instruction for a token bounty
+pub fn withdraw_contribution_token(funder: Pubkey, task_id: u64, token: TokenMint) -> Instruction {
+    let mut ix = withdraw_contribution(funder, task_id);
+    ix.accounts.extend(token_accounts(task_id, token, &[funder]));
+    ix
+}
+
+/// Build an AddCategory instruction (admin only)
+pub fn add_category(admin: Pubkey, id: u16, name: [u8; 32]) -> Instruction {
+    let config_address = config_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(category_pda(id).0, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::AddCategory as u8],
+            bytemuck::bytes_of(&AddCategoryArgs::new(id, name)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an UpdateCategory instruction (admin only)
+pub fn update_category(admin: Pubkey, id: u16, active: bool, name: [u8; 32]) -> Instruction {
+    let config_address = config_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(category_pda(id).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::UpdateCategory as u8],
+            bytemuck::bytes_of(&UpdateCategoryArgs::new(id, active, name)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a CreateTemplate instruction for template `template_id` (Config.template_count)
+pub fn create_template(owner: Pubkey, template_id: u64, args: TemplateArgs) -> Instruction {
+    let config_address = config_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(template_pda(template_id).0, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(category_pda(Tags::decode(&args.tags).category).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::CreateTemplate as u8],
+            bytemuck::bytes_of(&CreateTemplateArgs::new(args)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build an UpdateTemplate instruction (pass `Pubkey::default()` as `new_owner`
+/// to keep the current owner)
+pub fn update_template(owner: Pubkey, template_id: u64, new_owner: Pubkey, args: TemplateArgs) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(owner, true),
+            AccountMeta::new(template_pda(template_id).0, false),
+            AccountMeta::new_readonly(category_pda(Tags::decode(&args.tags).category).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::UpdateTemplate as u8],
+            bytemuck::bytes_of(&UpdateTemplateArgs::new(template_id, new_owner, args)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a CreateTaskFromTemplate instruction for a native SOL `template`, pinned
+/// to the template as read (fails if the owner updates it first)
+pub fn create_task_from_template(creator: Pubkey, task_id: u64, template: &Template) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(template_pda(template.id).0, false),
+            AccountMeta::new(creator, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(agent_pda(&creator).0, false),
+            AccountMeta::new_readonly(category_pda(Tags::decode(&template.tags).category).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::CreateTaskFromTemplate as u8],
+            bytemuck::bytes_of(&CreateTaskFromTemplateArgs::new(template.id, template.updated_at)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a CreateTaskFromTemplate instruction for a token `template` (bounty in
+/// `template.mint`, owned by `token_program`), funded from the creator's ATA
+pub fn create_task_from_template_token(
+    creator: Pubkey,
+    task_id: u64,
+    template: &Template,
+    token_program: Pubkey,
+) -> Instruction {
+    let token = TokenMint {
+        mint: template.mint,
+        token_program,
+    };
+    let mut ix = create_task_from_template(creator, task_id, template);
+    ix.accounts.extend(token_accounts(task_id, token, &[creator]));
+    ix
+}
+
+/// Build a CreateVault instruction for vault `vault_id` (Config.vault_count)
+pub fn create_vault(owner: Pubkey, vault_id: u64, args: CreateVaultArgs) -> Instruction {
+    let config_address = config_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(vault_pda(vault_id).0, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(category_pda(Tags::decode(&args.task.tags).category).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::CreateVault as u8],
+            bytemuck::bytes_of(&args).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a DepositVault instruction
+pub fn deposit_vault(owner: Pubkey, vault_id: u64, amount: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(vault_pda(vault_id).0, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::DepositVault as u8],
+            bytemuck::bytes_of(&DepositVaultArgs::new(vault_id, amount)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a WithdrawVault instruction
+pub fn withdraw_vault(owner: Pubkey, vault_id: u64, amount: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(vault_pda(vault_id).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::WithdrawVault as u8],
+            bytemuck::bytes_of(&WithdrawVaultArgs::new(vault_id, amount)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a CloseVault instruction
+pub fn close_vault(owner: Pubkey, vault_id: u64) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(vault_pda(vault_id).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::CloseVault as u8],
+            bytemuck::bytes_of(&CloseVaultArgs::new(vault_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a SpawnScheduledTask instruction (permissionless), where `task_id` is
+/// the current Config.task_count, and `owner` and `tags` are the vault's
+pub fn spawn_scheduled_task(
+    caller: Pubkey,
+    vault_id: u64,
+    task_id: u64,
+    owner: Pubkey,
+    tags: [u8; 16],
+) -> Instruction {
+    let config_address = config_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(caller, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(vault_pda(vault_id).0, false),
+            AccountMeta::new(task_pda(task_id).0, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(agent_pda(&owner).0, false),
+            AccountMeta::new_readonly(category_pda(Tags::decode(&tags).category).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::SpawnScheduledTask as u8],
+            bytemuck::bytes_of(&SpawnScheduledTaskArgs::new(vault_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a ReleaseStaleSlot instruction (permissionless)
+pub fn release_stale_slot(caller: Pubkey, task_id: u64, slot_index: u32, creator: Pubkey, claimer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+    let slot_address = slot_pda(task_id, slot_index).0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(caller, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(slot_address, false),
+            AccountMeta::new(claimer, false),
+            AccountMeta::new(creator, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::ReleaseStaleSlot as u8],
+            bytemuck::bytes_of(&ReleaseStaleSlotArgs::new(task_id, slot_index)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a ClaimExpiredSlot instruction (permissionless)
+pub fn claim_expired_slot(caller: Pubkey, task_id: u64, slot_index: u32, creator: Pubkey, claimer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+    let slot_address = slot_pda(task_id, slot_index).0;
+    let treasury_address = treasury_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(caller, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(slot_address, false),
+            AccountMeta::new(claimer, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(agent_pda(&creator).0, false),
+            AccountMeta::new(agent_pda(&claimer).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::ClaimExpiredSlot as u8],
+            bytemuck::bytes_of(&ClaimExpiredSlotArgs::new(task_id, slot_index)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a ClaimExpiredSlot instruction for a token bounty
+pub fn claim_expired_slot_token(
+    caller: Pubkey,
+    task_id: u64,
+    slot_index: u32,
+    creator: Pubkey,
+    claimer: Pubkey,
+    token: TokenMint,
+) -> Instruction {
+    let mut ix = claim_expired_slot(caller, task_id, slot_index, creator, claimer);
+    ix.accounts.extend(token_accounts(task_id, token, &[claimer, treasury_pda().0]));
+    ix
+}
+
+/// Build a ClaimExpiredMilestone instruction (permissionless)
+pub fn claim_expired_milestone(caller: Pubkey, task_id: u64, index: u8, creator: Pubkey, claimer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+    let milestone_address = milestone_pda(task_id, index).0;
+    let treasury_address = treasury_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(caller, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(milestone_address, false),
+            AccountMeta::new(claimer, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(agent_pda(&creator).0, false),
+            AccountMeta::new(agent_pda(&claimer).0, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::ClaimExpiredMilestone as u8],
+            bytemuck::bytes_of(&ClaimExpiredMilestoneArgs::new(task_id, index)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a ClaimExpiredMilestone instruction for a token bounty
+pub fn claim_expired_milestone_token(
+    caller: Pubkey,
+    task_id: u64,
+    index: u8,
+    creator: Pubkey,
+    claimer: Pubkey,
+    token: TokenMint,
+) -> Instruction {
+    let mut ix = claim_expired_milestone(caller, task_id, index, creator, claimer);
+    ix.accounts.extend(token_accounts(task_id, token, &[claimer, treasury_pda().0]));
+    ix
+}
+
+/// Build a ClaimContestShare instruction (permissionless, once the award window
+/// has passed) paying `entrant` its share
+pub fn claim_contest_share(caller: Pubkey, task_id: u64, entrant: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let task_address = task_pda(task_id).0;
+    let treasury_address = treasury_pda().0;
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(caller, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(task_address, false),
+            AccountMeta::new(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(submission_pda(task_id, &entrant).0, false),
+            AccountMeta::new(entrant, false),
+        ],
+        data: [
+            vec![BountyBoardInstruction::ClaimContestShare as u8],
+            bytemuck::bytes_of(&ClaimContestShareArgs::new(task_id)).to_vec(),
+        ]
+        .concat(),
+    }
+}
+
+/// Build a ClaimContestShare instruction for a token bounty
+pub fn claim_contest_share_token(caller: Pubkey, task_id: u64, entrant: Pubkey, token: TokenMint) -> Instruction {
+    let mut ix = claim_contest_share(caller, task_id, entrant);
+    ix.accounts.extend(token_accounts(task_id, token, &[entrant, treasury_pda().0]));
+    ix
+}
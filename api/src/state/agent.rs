@@ -0,0 +1,46 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+use steel::*;
+
+use crate::consts::TOTAL_BPS;
+
+use super::BountyBoardAccount;
+
+/// On-chain reputation profile for an agent wallet
+///
+/// PDA Seeds: ["agent", wallet]
+///
+/// Updated by the task lifecycle instructions whenever the wallet's Agent PDA is
+/// registered. Lamport totals only count native SOL bounties.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Agent {
+    /// Wallet this profile belongs to
+    pub wallet: Pubkey,             // 32
+    /// Tasks posted as creator
+    pub tasks_posted: u64,          // 8
+    /// Tasks completed as worker (approved, auto-released or won in dispute)
+    pub tasks_completed: u64,       // 8
+    /// Submissions rejected by the creator
+    pub tasks_rejected: u64,        // 8
+    /// Lamports earned as worker (after protocol fee)
+    pub total_earned: u64,          // 8
+    /// Lamports paid out to workers as creator
+    pub total_spent: u64,           // 8
+    /// Unix timestamp when the profile was registered
+    pub registered_at: i64,         // 8
+}
+
+impl Agent {
+    /// Share of judged submissions that ended up paid, in basis points
+    /// (0 with no history)
+    pub fn approval_rate_bps(&self) -> u64 {
+        let judged = self.tasks_completed.saturating_add(self.tasks_rejected);
+        if judged == 0 {
+            return 0;
+        }
+        (self.tasks_completed as u128 * TOTAL_BPS as u128 / judged as u128) as u64
+    }
+}
+
+account!(BountyBoardAccount, Agent);
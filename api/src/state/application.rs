@@ -0,0 +1,32 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+use steel::*;
+
+use super::BountyBoardAccount;
+
+/// Worker application to an application-mode task
+///
+/// PDA Seeds: ["application", task_id.to_le_bytes(), applicant]
+///
+/// Holds the applicant's claim bond (if the task sets one) as lamports until the
+/// application is accepted or closed.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Application {
+    /// Task this application is for
+    pub task_id: u64,               // 8
+    /// Worker applying for the task
+    pub applicant: Pubkey,          // 32
+    /// Price quote (never above the bounty)
+    pub price: u64,                 // 8
+    /// Estimated seconds to deliver
+    pub eta: i64,                   // 8
+    /// SHA256 hash of the pitch
+    pub pitch_hash: [u8; 32],       // 32
+    /// Claim bond deposited with the application
+    pub bond: u64,                  // 8
+    /// Unix timestamp when the application was submitted
+    pub created_at: i64,            // 8
+}
+
+account!(BountyBoardAccount, Application);
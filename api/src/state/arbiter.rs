@@ -0,0 +1,26 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+use steel::*;
+
+use super::BountyBoardAccount;
+
+/// Registered dispute arbiter
+///
+/// PDA Seeds: ["arbiter", index.to_le_bytes()]
+///
+/// The registry is kept dense: removing an arbiter moves the last one into its
+/// index, so indexes 0..Config.arbiter_count are always live.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Arbiter {
+    /// Arbiter wallet
+    pub wallet: Pubkey,             // 32
+    /// Registry index
+    pub index: u32,                 // 4
+    /// Padding for alignment
+    pub _padding: [u8; 4],          // 4
+    /// Unix timestamp when the arbiter was added
+    pub added_at: i64,              // 8
+}
+
+account!(BountyBoardAccount, Arbiter);
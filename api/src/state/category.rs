@@ -0,0 +1,34 @@
+use bytemuck::{Pod, Zeroable};
+use steel::*;
+
+use super::BountyBoardAccount;
+
+/// Admin-managed task category
+///
+/// PDA Seeds: ["category", category_id.to_le_bytes()]
+///
+/// Tasks reference a category by id in the first two bytes of their tags (see
+/// `crate::tags::Tags`); CreateTask and UpdateTask only accept active categories.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Category {
+    /// Category id (non-zero)
+    pub id: u16,                    // 2
+    /// 1 if new tasks may use this category
+    pub active: u8,                 // 1
+    /// Padding for alignment
+    pub _padding: [u8; 5],          // 5
+    /// UTF-8 display name, zero-padded
+    pub name: [u8; 32],             // 32
+    /// Unix timestamp when the category was added
+    pub created_at: i64,            // 8
+}
+
+impl Category {
+    /// Whether new tasks may use this category
+    pub fn is_active(&self) -> bool {
+        self.active != 0
+    }
+}
+
+account!(BountyBoardAccount, Category);
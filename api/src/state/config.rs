@@ -26,6 +26,39 @@ pub struct Config {
     pub total_completed: u64,    // 8
     /// Lamports required to file a dispute
     pub dispute_stake: u64,      // 8
+    /// Default seconds a claimer has to submit before the claim goes stale (0 = no limit)
+    pub default_work_window: i64, // 8
+    /// Admin proposed via ProposeAdmin, pending AcceptAdmin (Pubkey::default() if none)
+    pub pending_admin: Pubkey,   // 32
+    /// Cumulative lamports paid into the treasury (native fees and forfeited stakes)
+    pub total_fees_collected: u64, // 8
+    /// Number of registered arbiters (Arbiter PDAs 0..arbiter_count)
+    pub arbiter_count: u32,      // 4
+    /// Arbiters assigned to each dispute (0 = admin resolves)
+    pub arbiters_per_dispute: u8, // 1
+    /// Padding for alignment
+    pub _padding2: [u8; 3],      // 3
+    /// Round-robin position of the next arbiter to assign
+    pub arbiter_cursor: u64,     // 8
+    /// Seconds after opening before an unresolved dispute can be expired
+    pub dispute_resolution_timeout: i64, // 8
+    /// Total number of task templates created
+    pub template_count: u64,     // 8
+    /// Total number of scheduled-task vaults created
+    pub vault_count: u64,        // 8
+}
+
+impl Config {
+    /// Arbiter indexes assigned to the next dispute, in panel order.
+    /// Panels rotate round-robin through the registry.
+    pub fn next_panel(&self) -> Vec<u32> {
+        if self.arbiter_count == 0 {
+            return Vec::new();
+        }
+        let size = (self.arbiters_per_dispute as u32).min(self.arbiter_count);
+        let start = (self.arbiter_cursor % self.arbiter_count as u64) as u32;
+        (0..size).map(|i| (start + i) % self.arbiter_count).collect()
+    }
 }
 
 account!(BountyBoardAccount, Config);
@@ -0,0 +1,26 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+use steel::*;
+
+use super::BountyBoardAccount;
+
+/// A funder's running contribution to a task's bounty
+///
+/// PDA Seeds: ["contribution", task_id.to_le_bytes(), funder]
+///
+/// Created by the funder's first IncreaseBounty and closed by WithdrawContribution
+/// once the task is finished, paying out the funder's pro-rata share of any refund.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Contribution {
+    /// Task funded
+    pub task_id: u64,               // 8
+    /// Wallet that contributed
+    pub funder: Pubkey,             // 32
+    /// Total contributed (lamports, or token base units for token bounties)
+    pub amount: u64,                // 8
+    /// Unix timestamp of the first contribution
+    pub created_at: i64,            // 8
+}
+
+account!(BountyBoardAccount, Contribution);
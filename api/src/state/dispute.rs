@@ -0,0 +1,225 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+use steel::*;
+
+use crate::consts::{MAX_DISPUTE_ARBITERS, TOTAL_BPS, VOTE_CLAIMER, VOTE_CREATOR};
+
+use super::BountyBoardAccount;
+
+/// Arbiter panel and votes for a disputed task
+///
+/// PDA Seeds: ["dispute", task_id.to_le_bytes()]
+///
+/// Created by Dispute (rent paid by the claimer) and closed when the dispute is
+/// settled. A panel of zero arbiters leaves the dispute to the admin. Both parties
+/// can attach evidence hashes for the arbiters to review.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Dispute {
+    /// Disputed task
+    pub task_id: u64,                               // 8
+    /// Task creator
+    pub creator: Pubkey,                            // 32
+    /// Claimer who opened the dispute
+    pub claimer: Pubkey,                            // 32
+    /// Lamports the claimer staked (held in the Task PDA)
+    pub stake: u64,                                 // 8
+    /// Assigned arbiter wallets (first `arbiter_count` are used)
+    pub arbiters: [Pubkey; MAX_DISPUTE_ARBITERS],   // 160
+    /// Vote of each arbiter (VOTE_NONE, VOTE_CREATOR or VOTE_CLAIMER)
+    pub votes: [u8; MAX_DISPUTE_ARBITERS],          // 5
+    /// Number of assigned arbiters
+    pub arbiter_count: u8,                          // 1
+    /// 1 once the creator has posted a matching counter-stake
+    pub creator_staked: u8,                         // 1
+    /// Padding for alignment
+    pub _padding: [u8; 1],                          // 1
+    /// Unix timestamp when the dispute was opened
+    pub opened_at: i64,                             // 8
+    /// Unix timestamp after which votes are closed
+    pub voting_deadline: i64,                       // 8
+    /// Unix timestamp after which anyone can expire the dispute (refunding both sides)
+    pub expires_at: i64,                            // 8
+    /// Unix timestamp by which the creator must counter-stake or lose by default
+    pub counter_stake_deadline: i64,                // 8
+    /// Latest evidence hash submitted by the creator (zero if none)
+    pub creator_evidence: [u8; 32],                 // 32
+    /// Latest evidence hash submitted by the claimer (zero if none)
+    pub claimer_evidence: [u8; 32],                 // 32
+}
+
+impl Dispute {
+    /// Votes cast for the creator and for the claimer
+    pub fn tally(&self) -> (usize, usize) {
+        let votes = &self.votes[..self.arbiter_count as usize];
+        (
+            votes.iter().filter(|vote| **vote == VOTE_CREATOR).count(),
+            votes.iter().filter(|vote| **vote == VOTE_CLAIMER).count(),
+        )
+    }
+
+    /// Whether the creator has posted a matching counter-stake
+    pub fn is_contested(&self) -> bool {
+        self.creator_staked != 0
+    }
+
+    /// Lamports the creator has at stake (zero until counter-staked)
+    pub fn creator_stake(&self) -> u64 {
+        if self.is_contested() {
+            self.stake
+        } else {
+            0
+        }
+    }
+
+    /// The dispute's decision at `now`, if any: VOTE_CREATOR or VOTE_CLAIMER.
+    ///
+    /// Until the creator counter-stakes there is no decision, and once the
+    /// counter-stake deadline passes without one the claimer wins by default.
+    /// Otherwise a strict majority of the panel decides immediately. After the
+    /// voting deadline, a plurality of the votes cast decides; a tie has no decision.
+    pub fn decision(&self, now: i64) -> Option<u8> {
+        if !self.is_contested() {
+            return (now > self.counter_stake_deadline).then_some(VOTE_CLAIMER);
+        }
+
+        let (creator, claimer) = self.tally();
+        let panel = self.arbiter_count as usize;
+        if creator * 2 > panel {
+            return Some(VOTE_CREATOR);
+        }
+        if claimer * 2 > panel {
+            return Some(VOTE_CLAIMER);
+        }
+        if now <= self.voting_deadline || creator == claimer {
+            return None;
+        }
+        Some(if creator > claimer { VOTE_CREATOR } else { VOTE_CLAIMER })
+    }
+}
+
+/// How a dispute settlement divides the funds held for a task
+///
+/// The claimer receives `claimer_share_bps` of the bounty, minus the protocol fee
+/// on that share; the creator is refunded the rest. Each side's dispute stake is
+/// forfeited in proportion to how much it lost: the claimer keeps the same share of
+/// their stake and claim bond, the creator forfeits that share of their
+/// counter-stake. Every amount rounds down and the other side takes the remainder,
+/// so the parts always add back up to the inputs:
+///
+/// ```
+/// use bountyboard_api::state::DisputeSplit;
+///
+/// // 1001 lamports at 33.33% with a 2% fee
+/// let split = DisputeSplit::new(1001, 10, 10, 7, 3333, 200).unwrap();
+/// assert_eq!(split.claimer_payment + split.fee, 333);
+/// assert_eq!(split.fee, 6);
+/// assert_eq!(split.creator_refund, 668);
+/// assert_eq!(split.stake_returned, 3);
+/// assert_eq!(split.stake_forfeited, 7);
+/// assert_eq!(split.creator_stake_forfeited, 3);
+/// assert_eq!(split.creator_stake_returned, 7);
+/// assert_eq!(split.bond_returned, 2);
+/// assert_eq!(split.bond_forfeited, 5);
+/// ```
+///
+/// Every combination of share and fee leaves nothing stranded:
+///
+/// ```
+/// use bountyboard_api::state::DisputeSplit;
+///
+/// for bounty in [0, 1, 3, 999, 1_000_000_007] {
+///     for share in [0, 1, 2500, 3333, 5000, 6667, 9999, 10_000] {
+///         for fee in [0, 1, 200, 10_000] {
+///             let split = DisputeSplit::new(bounty, 13, 11, 5, share, fee).unwrap();
+///             assert_eq!(split.claimer_payment + split.fee + split.creator_refund, bounty);
+///             assert_eq!(split.stake_returned + split.stake_forfeited, 13);
+///             assert_eq!(split.creator_stake_returned + split.creator_stake_forfeited, 11);
+///             assert_eq!(split.bond_returned + split.bond_forfeited, 5);
+///         }
+///     }
+/// }
+/// ```
+///
+/// The extremes match an outright win for either side:
+///
+/// ```
+/// use bountyboard_api::state::DisputeSplit;
+///
+/// let claimer = DisputeSplit::new(1_000, 50, 50, 20, 10_000, 200).unwrap();
+/// assert_eq!((claimer.claimer_payment, claimer.fee, claimer.creator_refund), (980, 20, 0));
+/// assert_eq!((claimer.stake_returned, claimer.bond_returned), (50, 20));
+/// assert_eq!(claimer.creator_stake_forfeited, 50);
+///
+/// let creator = DisputeSplit::new(1_000, 50, 50, 20, 0, 200).unwrap();
+/// assert_eq!((creator.claimer_payment, creator.fee, creator.creator_refund), (0, 0, 1_000));
+/// assert_eq!((creator.stake_forfeited, creator.bond_forfeited), (50, 20));
+/// assert_eq!(creator.creator_stake_returned, 50);
+///
+/// assert!(DisputeSplit::new(1_000, 50, 50, 20, 10_001, 200).is_none());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DisputeSplit {
+    /// Bounty paid to the claimer, net of the protocol fee
+    pub claimer_payment: u64,
+    /// Protocol fee on the claimer's share of the bounty
+    pub fee: u64,
+    /// Bounty refunded to the creator
+    pub creator_refund: u64,
+    /// Claimer's dispute stake returned to the claimer
+    pub stake_returned: u64,
+    /// Claimer's dispute stake forfeited (to arbiters and the treasury)
+    pub stake_forfeited: u64,
+    /// Creator's counter-stake returned to the creator
+    pub creator_stake_returned: u64,
+    /// Creator's counter-stake forfeited (to arbiters and the treasury)
+    pub creator_stake_forfeited: u64,
+    /// Claim bond returned to the claimer
+    pub bond_returned: u64,
+    /// Claim bond forfeited to the creator
+    pub bond_forfeited: u64,
+}
+
+impl DisputeSplit {
+    /// Splits `bounty`, both dispute stakes and `claim_bond` for a claimer share of
+    /// `claimer_share_bps`. Returns None if the share or fee exceeds TOTAL_BPS.
+    pub fn new(
+        bounty: u64,
+        stake: u64,
+        creator_stake: u64,
+        claim_bond: u64,
+        claimer_share_bps: u16,
+        protocol_fee_bps: u16,
+    ) -> Option<Self> {
+        if claimer_share_bps as u64 > TOTAL_BPS || protocol_fee_bps as u64 > TOTAL_BPS {
+            return None;
+        }
+
+        let claimer_gross = bps_of(bounty, claimer_share_bps)?;
+        let fee = bps_of(claimer_gross, protocol_fee_bps)?;
+        let stake_returned = bps_of(stake, claimer_share_bps)?;
+        let creator_stake_forfeited = bps_of(creator_stake, claimer_share_bps)?;
+        let bond_returned = bps_of(claim_bond, claimer_share_bps)?;
+
+        Some(Self {
+            claimer_payment: claimer_gross - fee,
+            fee,
+            creator_refund: bounty - claimer_gross,
+            stake_returned,
+            stake_forfeited: stake - stake_returned,
+            creator_stake_returned: creator_stake - creator_stake_forfeited,
+            creator_stake_forfeited,
+            bond_returned,
+            bond_forfeited: claim_bond - bond_returned,
+        })
+    }
+}
+
+/// `amount * bps / TOTAL_BPS`, rounded down (never more than `amount` for
+/// bps <= TOTAL_BPS)
+fn bps_of(amount: u64, bps: u16) -> Option<u64> {
+    let scaled = (amount as u128).checked_mul(bps as u128)? / TOTAL_BPS as u128;
+    u64::try_from(scaled).ok()
+}
+
+account!(BountyBoardAccount, Dispute);
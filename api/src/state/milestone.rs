@@ -0,0 +1,32 @@
+use bytemuck::{Pod, Zeroable};
+use steel::*;
+
+use super::BountyBoardAccount;
+
+/// A payment tranche of a milestone task
+///
+/// PDA Seeds: ["milestone", task_id.to_le_bytes(), index]
+///
+/// The tranche's funds stay in the Task escrow until the creator approves it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Milestone {
+    /// Task this milestone belongs to
+    pub task_id: u64,               // 8
+    /// Amount released when this milestone is approved
+    pub amount: u64,                // 8
+    /// SHA256 hash of the milestone description
+    pub description_hash: [u8; 32], // 32
+    /// SHA256 hash of the submitted proof
+    pub proof_hash: [u8; 32],       // 32
+    /// Position within the task (milestones are worked in order)
+    pub index: u8,                  // 1
+    /// Milestone status: 0=Pending, 1=Submitted, 2=Released
+    pub status: u8,                 // 1
+    /// Padding for alignment
+    pub _padding: [u8; 6],          // 6
+    /// Unix timestamp when proof was submitted (0 = not submitted)
+    pub submitted_at: i64,          // 8
+}
+
+account!(BountyBoardAccount, Milestone);
@@ -1,8 +1,32 @@
+mod agent;
+mod application;
+mod arbiter;
+mod category;
 mod config;
+mod contribution;
+mod dispute;
+mod milestone;
+mod slot;
+mod submission;
 mod task;
+mod template;
+mod vault;
+mod treasury;
 
+pub use agent::*;
+pub use application::*;
+pub use arbiter::*;
+pub use category::*;
 pub use config::*;
+pub use contribution::*;
+pub use dispute::*;
+pub use milestone::*;
+pub use slot::*;
+pub use submission::*;
 pub use task::*;
+pub use template::*;
+pub use vault::*;
+pub use treasury::*;
 
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
@@ -14,4 +38,28 @@ pub enum BountyBoardAccount {
     Config = 0,
     /// Task escrow account
     Task = 1,
+    /// Agent reputation account
+    Agent = 2,
+    /// Milestone tranche of a task
+    Milestone = 3,
+    /// Protocol fee treasury
+    Treasury = 4,
+    /// Worker application to an application-mode task
+    Application = 5,
+    /// Contest entry
+    Submission = 6,
+    /// Worker slot of a multi-slot task
+    Slot = 7,
+    /// Registered dispute arbiter
+    Arbiter = 8,
+    /// Arbiter panel and votes for a disputed task
+    Dispute = 9,
+    /// A funder's contribution to a task's bounty
+    Contribution = 10,
+    /// Admin-managed task category
+    Category = 11,
+    /// Reusable task preset
+    Template = 12,
+    /// Scheduled-task vault
+    Vault = 13,
 }
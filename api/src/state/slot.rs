@@ -0,0 +1,34 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+use steel::*;
+
+use super::BountyBoardAccount;
+
+/// One worker's slot on a multi-slot task
+///
+/// PDA Seeds: ["slot", task_id.to_le_bytes(), slot_index.to_le_bytes()]
+///
+/// Created by ClaimSlot and closed (rent back to the worker) when the slot is
+/// approved or rejected. Indexes are never reused.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Slot {
+    /// Task this slot belongs to
+    pub task_id: u64,               // 8
+    /// Worker who claimed the slot
+    pub claimer: Pubkey,            // 32
+    /// SHA256 hash of the submitted proof
+    pub proof_hash: [u8; 32],       // 32
+    /// Slot index
+    pub index: u32,                 // 4
+    /// Slot status: 0=Claimed, 1=Submitted
+    pub status: u8,                 // 1
+    /// Padding for alignment
+    pub _padding: [u8; 3],          // 3
+    /// Unix timestamp when the slot was claimed
+    pub claimed_at: i64,            // 8
+    /// Unix timestamp when work was submitted (0 = not submitted)
+    pub submitted_at: i64,          // 8
+}
+
+account!(BountyBoardAccount, Slot);
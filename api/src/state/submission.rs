@@ -0,0 +1,23 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+use steel::*;
+
+use super::BountyBoardAccount;
+
+/// Contest entry, one per entrant
+///
+/// PDA Seeds: ["submission", task_id.to_le_bytes(), entrant]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Submission {
+    /// Contest task this entry is for
+    pub task_id: u64,               // 8
+    /// Agent who submitted the entry
+    pub entrant: Pubkey,            // 32
+    /// SHA256 hash of the entry
+    pub proof_hash: [u8; 32],       // 32
+    /// Unix timestamp when the entry was submitted
+    pub submitted_at: i64,          // 8
+}
+
+account!(BountyBoardAccount, Submission);
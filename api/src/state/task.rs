@@ -1,14 +1,20 @@
 use bytemuck::{Pod, Zeroable};
-use solana_program::pubkey::Pubkey;
+use solana_program::{hash::hashv, pubkey::Pubkey};
 use steel::*;
 
+use crate::consts::{
+    DEADLINE_GRACE_PERIOD, MAX_TASK_DEPENDENCIES, TASK_FLAG_APPLICATIONS, TASK_FLAG_CONTEST,
+};
+
 use super::BountyBoardAccount;
 
 /// Task escrow account
 ///
 /// PDA Seeds: ["task", task_id.to_le_bytes()]
 ///
-/// SOL is stored directly in this PDA as lamports (escrow pattern).
+/// Native SOL bounties are stored directly in this PDA as lamports (escrow pattern).
+/// SPL Token / Token-2022 bounties are held in the Task PDA's associated token
+/// account for `mint`. The claim bond is always held as lamports.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
 pub struct Task {
@@ -16,7 +22,8 @@ pub struct Task {
     pub id: u64,                    // 8
     /// Creator who posted the task
     pub creator: Pubkey,            // 32
-    /// Agent who claimed the task (Pubkey::default() if unclaimed)
+    /// Agent who claimed the task (Pubkey::default() if unclaimed).
+    /// Kept while REJECTED so the rejected claimer can dispute.
     pub claimer: Pubkey,            // 32
     /// Bounty amount in lamports
     pub bounty: u64,                // 8
@@ -24,10 +31,20 @@ pub struct Task {
     pub description_hash: [u8; 32], // 32
     /// SHA256 hash of the submitted proof
     pub proof_hash: [u8; 32],       // 32
-    /// Task status: 0=Open, 1=Claimed, 2=Submitted, 3=Completed, 4=Cancelled, 5=Disputed
+    /// Task status: 0=Open, 1=Claimed, 2=Submitted, 3=Completed, 4=Cancelled, 5=Disputed,
+    /// 6=Rejected
     pub status: u8,                 // 1
+    /// Number of milestones declared (0 = single payout)
+    pub milestone_count: u8,        // 1
+    /// Number of milestones approved and paid out
+    pub milestones_released: u8,    // 1
+    /// Task flags (TASK_FLAG_*)
+    pub flags: u8,                  // 1
+    /// Set when a multi-slot task is cancelled or expired with slots in progress:
+    /// no new slots can be claimed and freed slots are refunded
+    pub slots_closed: u8,           // 1
     /// Padding for alignment
-    pub _padding: [u8; 7],          // 7
+    pub _padding: [u8; 3],          // 3
     /// Unix timestamp when task was created
     pub created_at: i64,            // 8
     /// Deadline unix timestamp (0 = no deadline)
@@ -38,6 +55,178 @@ pub struct Task {
     pub submitted_at: i64,          // 8
     /// Unix timestamp when task was claimed (0 = not claimed)
     pub claimed_at: i64,            // 8
+    /// Bounty mint (Pubkey::default() for native SOL)
+    pub mint: Pubkey,               // 32
+    /// Unix timestamp when work was rejected (0 = not rejected)
+    pub rejected_at: i64,           // 8
+    /// Sum of declared milestone amounts
+    pub milestones_total: u64,      // 8
+    /// Amount of the bounty already paid out through milestones or lapsed-contest
+    /// shares
+    pub released: u64,              // 8
+    /// Seconds the claimer has to submit (0 = Config default)
+    pub work_window: i64,           // 8
+    /// Lamports the claimer stakes at ClaimTask, held in this PDA until the claim ends
+    pub claim_bond: u64,            // 8
+    /// Number of contest entries submitted
+    pub entries: u64,               // 8
+    /// Number of independent worker slots (0 = single-worker task)
+    pub slots: u32,                 // 4
+    /// Slots claimed or paid out
+    pub slots_taken: u32,           // 4
+    /// Slots paid out
+    pub slots_done: u32,            // 4
+    /// Index of the next Slot PDA to create
+    pub next_slot: u32,             // 4
+    /// Slot submissions rejected so far (RejectSlot allows at most `slots`)
+    pub slots_rejected: u32,        // 4
+    /// Padding for alignment
+    pub _padding2: [u8; 4],         // 4
+    /// Payout per slot (bounty = bounty_per_slot * slots)
+    pub bounty_per_slot: u64,       // 8
+    /// Contributors' part of the bounty still in escrow (never more than `bounty`)
+    pub contributed: u64,           // 8
+    /// Sum of open Contribution amounts, the basis of WithdrawContribution's
+    /// pro-rata split
+    pub contributed_open: u64,      // 8
+    /// Contributors' share of refunds, held until WithdrawContribution
+    pub refund_pool: u64,           // 8
+    /// Open Contribution PDAs
+    pub contributors: u32,          // 4
+    /// Unclaimed tasks listing this one as a prerequisite; it cannot be closed
+    /// while any remain
+    pub dependents: u32,            // 4
+    /// Only this wallet may claim (Pubkey::default() = no direct hire)
+    pub allowed_claimer: Pubkey,    // 32
+    /// Merkle root of allowed claimers (zero = no allowlist), see `verify_claimer_proof`
+    pub claimer_root: [u8; 32],     // 32
+    /// Minimum completed tasks on the claimer's Agent PDA (0 = no requirement)
+    pub min_completions: u32,       // 4
+    /// Minimum claimer approval rate in basis points (0 = no requirement)
+    pub min_approval_bps: u16,      // 2
+    /// Number of prerequisite tasks in `dependencies`
+    pub dependency_count: u8,       // 1
+    /// Set while this task counts towards its prerequisites' `dependents`: from
+    /// CreateTask until it is first claimed, cancelled or expired
+    pub prerequisites_held: u8,     // 1
+    /// IDs of tasks that must be COMPLETED before this one can be claimed
+    pub dependencies: [u64; MAX_TASK_DEPENDENCIES], // 32
+}
+
+impl Task {
+    /// Whether the bounty is escrowed in an SPL token rather than lamports
+    pub fn is_token(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+
+    /// Whether the bounty is paid out per milestone
+    pub fn has_milestones(&self) -> bool {
+        self.milestone_count > 0
+    }
+
+    /// Whether workers must apply instead of claiming directly
+    pub fn requires_application(&self) -> bool {
+        self.flags & TASK_FLAG_APPLICATIONS != 0
+    }
+
+    /// Whether the task is a contest paid out by AwardContest
+    pub fn is_contest(&self) -> bool {
+        self.flags & TASK_FLAG_CONTEST != 0
+    }
+
+    /// Whether the bounty is split into independent worker slots
+    pub fn has_slots(&self) -> bool {
+        self.slots > 0
+    }
+
+    /// Whether work submitted at `now` is too late: past the deadline (if set)
+    /// plus DEADLINE_GRACE_PERIOD
+    pub fn submission_closed(&self, now: i64) -> bool {
+        self.deadline > 0 && now > self.deadline.saturating_add(DEADLINE_GRACE_PERIOD)
+    }
+
+    /// Contributors' pro-rata part of a `refund` of the bounty (rounded down; the
+    /// creator takes the remainder). None on overflow.
+    pub fn contributors_share(&self, refund: u64) -> Option<u64> {
+        if self.bounty == 0 {
+            return Some(0);
+        }
+        let share = (refund as u128).checked_mul(self.contributed as u128)? / self.bounty as u128;
+        u64::try_from(share).ok().map(|share| share.min(refund))
+    }
+
+    /// Whether claiming is restricted to a direct hire or an allowlist
+    pub fn is_private(&self) -> bool {
+        self.allowed_claimer != Pubkey::default() || self.claimer_root != [0u8; 32]
+    }
+
+    /// Whether `claimer` may claim the task: always for public tasks, otherwise
+    /// if it is the direct hire or `proof` shows it is on the allowlist
+    pub fn is_allowed_claimer(&self, claimer: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        if !self.is_private() {
+            return true;
+        }
+        (self.allowed_claimer != Pubkey::default() && self.allowed_claimer == *claimer)
+            || (self.claimer_root != [0u8; 32] && verify_claimer_proof(&self.claimer_root, claimer, proof))
+    }
+
+    /// Whether claimers need a registered Agent PDA meeting minimum reputation
+    pub fn has_reputation_gate(&self) -> bool {
+        self.min_completions > 0 || self.min_approval_bps > 0
+    }
+
+    /// Prerequisite task IDs (empty if `dependency_count` is out of range)
+    pub fn dependencies(&self) -> &[u64] {
+        self.dependencies
+            .get(..self.dependency_count as usize)
+            .unwrap_or(&[])
+    }
+
+    /// Prerequisite task IDs this task still counts towards (see `prerequisites_held`)
+    pub fn held_prerequisites(&self) -> &[u64] {
+        if self.prerequisites_held == 0 {
+            return &[];
+        }
+        self.dependencies()
+    }
+
+    /// Bounty still held in escrow (not yet released through milestones)
+    pub fn remaining(&self) -> u64 {
+        self.bounty.saturating_sub(self.released)
+    }
+}
+
+/// Leaf of a claimer allowlist merkle tree: sha256(claimer)
+pub fn claimer_leaf(claimer: &Pubkey) -> [u8; 32] {
+    hashv(&[claimer.as_ref()]).to_bytes()
+}
+
+/// Verifies that `claimer` is in the allowlist with merkle root `root`.
+///
+/// Parents hash their two children in sorted order, sha256(min || max), so a
+/// proof is just the sibling hashes from the leaf up:
+///
+/// ```
+/// use bountyboard_api::state::{claimer_leaf, verify_claimer_proof};
+/// use solana_program::{hash::hashv, pubkey::Pubkey};
+///
+/// let (alice, bob, carol) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+/// let (a, b) = (claimer_leaf(&alice), claimer_leaf(&bob));
+/// let root = if a <= b { hashv(&[&a, &b]) } else { hashv(&[&b, &a]) }.to_bytes();
+///
+/// assert!(verify_claimer_proof(&root, &alice, &[b]));
+/// assert!(verify_claimer_proof(&root, &bob, &[a]));
+/// assert!(!verify_claimer_proof(&root, &carol, &[a]));
+/// ```
+pub fn verify_claimer_proof(root: &[u8; 32], claimer: &Pubkey, proof: &[[u8; 32]]) -> bool {
+    let node = proof.iter().fold(claimer_leaf(claimer), |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    node == *root
 }
 
 account!(BountyBoardAccount, Task);
@@ -0,0 +1,48 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+use steel::*;
+
+use super::BountyBoardAccount;
+
+/// Reusable task preset, instantiated with CreateTaskFromTemplate
+///
+/// PDA Seeds: ["template", template_id.to_le_bytes()]
+///
+/// Only the owner can update a template; anyone can post (and fund) a task from it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Template {
+    /// Unique template ID (sequential)
+    pub id: u64,                    // 8
+    /// Wallet allowed to update the template
+    pub owner: Pubkey,              // 32
+    /// Default bounty (lamports, or base units of `mint`)
+    pub bounty: u64,                // 8
+    /// Bounty mint (Pubkey::default() for native SOL)
+    pub mint: Pubkey,               // 32
+    /// Seconds from instantiation to the task deadline (0 = no deadline)
+    pub deadline_offset: i64,       // 8
+    /// Seconds the claimer has to submit (0 = Config default)
+    pub work_window: i64,           // 8
+    /// Lamports the claimer must stake at ClaimTask (0 = no bond)
+    pub claim_bond: u64,            // 8
+    /// SHA256 hash of the task description
+    pub description_hash: [u8; 32], // 32
+    /// Tags in the `crate::tags::Tags` layout
+    pub tags: [u8; 16],             // 16
+    /// Minimum completed tasks a claimer needs (0 = no requirement)
+    pub min_completions: u32,       // 4
+    /// Minimum claimer approval rate in basis points (0 = no requirement)
+    pub min_approval_bps: u16,      // 2
+    /// Task flags (TASK_FLAG_*)
+    pub flags: u8,                  // 1
+    /// Padding for alignment
+    pub _padding: [u8; 1],          // 1
+    /// Tasks created from this template
+    pub uses: u64,                  // 8
+    /// Unix timestamp of the last create or update; strictly increases on every
+    /// update, so CreateTaskFromTemplate callers can pin the terms they read
+    pub updated_at: i64,            // 8
+}
+
+account!(BountyBoardAccount, Template);
@@ -0,0 +1,19 @@
+use bytemuck::{Pod, Zeroable};
+use steel::*;
+
+use super::BountyBoardAccount;
+
+/// Protocol treasury that collects fees and forfeited dispute stakes
+///
+/// PDA Seeds: ["treasury"]
+///
+/// Holds native SOL fees as lamports above its rent-exempt minimum. Token fees are
+/// held in the treasury's associated token account for each mint.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Treasury {
+    /// Total lamports withdrawn by the admin
+    pub total_withdrawn: u64,       // 8
+}
+
+account!(BountyBoardAccount, Treasury);
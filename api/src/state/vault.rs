@@ -0,0 +1,54 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+use steel::*;
+
+use super::BountyBoardAccount;
+
+/// Prepaid schedule that posts a task every `interval` seconds
+///
+/// PDA Seeds: ["vault", vault_id.to_le_bytes()]
+///
+/// The owner deposits SOL; SpawnScheduledTask (permissionless) pays each task's
+/// bounty and rent out of `balance` and makes the owner its creator.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct Vault {
+    /// Unique vault ID (sequential)
+    pub id: u64,                    // 8
+    /// Wallet that funds the vault and creates the spawned tasks
+    pub owner: Pubkey,              // 32
+    /// Lamports available for spawning (excludes the vault's own rent)
+    pub balance: u64,               // 8
+    /// Seconds between spawns
+    pub interval: i64,              // 8
+    /// Unix timestamp from which the next task can be spawned
+    pub next_spawn_at: i64,         // 8
+    /// Bounty per spawned task (lamports)
+    pub bounty: u64,                // 8
+    /// Seconds from spawning to the task deadline (0 = no deadline)
+    pub deadline_offset: i64,       // 8
+    /// Seconds the claimer has to submit (0 = Config default)
+    pub work_window: i64,           // 8
+    /// Lamports the claimer must stake at ClaimTask (0 = no bond)
+    pub claim_bond: u64,            // 8
+    /// SHA256 hash of the task description
+    pub description_hash: [u8; 32], // 32
+    /// Tags in the `crate::tags::Tags` layout
+    pub tags: [u8; 16],             // 16
+    /// Minimum completed tasks a claimer needs (0 = no requirement)
+    pub min_completions: u32,       // 4
+    /// Minimum claimer approval rate in basis points (0 = no requirement)
+    pub min_approval_bps: u16,      // 2
+    /// Task flags (TASK_FLAG_*)
+    pub flags: u8,                  // 1
+    /// Padding for alignment
+    pub _padding: [u8; 1],          // 1
+    /// Tasks spawned so far
+    pub spawned: u64,               // 8
+    /// ID of the most recently spawned task
+    pub last_task_id: u64,          // 8
+    /// Unix timestamp when created
+    pub created_at: i64,            // 8
+}
+
+account!(BountyBoardAccount, Vault);
@@ -0,0 +1,102 @@
+//! Structured encoding of `Task::tags`
+
+use crate::consts::{MAX_TAG_SKILLS, TAG_FLAGS_ALL};
+
+/// Decoded form of a task's 16-byte `tags` field
+///
+/// Byte layout (all integers little-endian):
+/// - `[0..2]` category id (0 = uncategorized, otherwise a registered Category PDA)
+/// - `[2..4]` tag flags (TAG_FLAG_*)
+/// - `[4..16]` up to MAX_TAG_SKILLS skill ids, zero-padded
+///
+/// ```
+/// use bountyboard_api::{consts::TAG_FLAG_URGENT, tags::Tags};
+///
+/// let tags = Tags::new(3).with_flags(TAG_FLAG_URGENT).with_skills(&[7, 42]);
+/// let bytes = tags.encode();
+/// assert_eq!(bytes[..6], [3, 0, 1, 0, 7, 0]);
+///
+/// let decoded = Tags::decode(&bytes);
+/// assert_eq!(decoded, tags);
+/// assert_eq!(decoded.skills().collect::<Vec<_>>(), [7, 42]);
+/// assert!(decoded.is_valid());
+///
+/// // Skills must be packed and unique
+/// assert!(!Tags::new(3).with_skills(&[0, 7]).is_valid());
+/// assert!(!Tags::new(3).with_skills(&[7, 7]).is_valid());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Tags {
+    /// Category id (0 = uncategorized)
+    pub category: u16,
+    /// Tag flags (TAG_FLAG_*)
+    pub flags: u16,
+    /// Skill ids, non-zero ids first
+    pub skills: [u16; MAX_TAG_SKILLS],
+}
+
+impl Tags {
+    pub fn new(category: u16) -> Self {
+        Self {
+            category,
+            ..Self::default()
+        }
+    }
+    pub fn with_flags(mut self, flags: u16) -> Self {
+        self.flags = flags;
+        self
+    }
+    /// Sets the skill ids (truncated to MAX_TAG_SKILLS)
+    pub fn with_skills(mut self, skills: &[u16]) -> Self {
+        let len = skills.len().min(MAX_TAG_SKILLS);
+        self.skills = [0; MAX_TAG_SKILLS];
+        self.skills[..len].copy_from_slice(&skills[..len]);
+        self
+    }
+
+    /// Packs the tags into the on-chain `[u8; 16]` layout
+    pub fn encode(&self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[0..2].copy_from_slice(&self.category.to_le_bytes());
+        bytes[2..4].copy_from_slice(&self.flags.to_le_bytes());
+        for (chunk, skill) in bytes[4..].chunks_exact_mut(2).zip(self.skills) {
+            chunk.copy_from_slice(&skill.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Unpacks the on-chain `[u8; 16]` layout (does not validate)
+    pub fn decode(bytes: &[u8; 16]) -> Self {
+        let mut skills = [0u16; MAX_TAG_SKILLS];
+        for (skill, chunk) in skills.iter_mut().zip(bytes[4..].chunks_exact(2)) {
+            *skill = u16::from_le_bytes([chunk[0], chunk[1]]);
+        }
+        Self {
+            category: u16::from_le_bytes([bytes[0], bytes[1]]),
+            flags: u16::from_le_bytes([bytes[2], bytes[3]]),
+            skills,
+        }
+    }
+
+    /// Non-zero skill ids
+    pub fn skills(&self) -> impl Iterator<Item = u16> + '_ {
+        self.skills.iter().copied().take_while(|skill| *skill != 0)
+    }
+
+    /// Whether the flags are known and the skills are packed (no zero before a
+    /// non-zero id) and unique
+    pub fn is_valid(&self) -> bool {
+        if self.flags & !TAG_FLAGS_ALL != 0 {
+            return false;
+        }
+        let count = self.skills().count();
+        if self.skills[count..].iter().any(|skill| *skill != 0) {
+            return false;
+        }
+        let skills = &self.skills[..count];
+        skills
+            .iter()
+            .enumerate()
+            .all(|(i, skill)| !skills[..i].contains(skill))
+    }
+}
@@ -0,0 +1,40 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use steel::*;
+use bountyboard_api::{
+    error::BountyBoardError,
+    state::Config,
+};
+
+/// Process AcceptAdmin instruction
+///
+/// The admin proposed via ProposeAdmin takes over the role.
+///
+/// Accounts:
+/// 0. `[signer]` Proposed admin wallet
+/// 1. `[writable]` Config PDA
+pub fn process_accept_admin(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let [new_admin_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    new_admin_info.is_signer()?;
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+
+    // Only the pending admin can accept
+    if config.pending_admin == Pubkey::default() || config.pending_admin != *new_admin_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+
+    solana_program::msg!("BountyBoard: Admin transferred to {}", config.admin);
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::AcceptApplicationArgs,
+    state::{Application, Config, Task},
+};
+
+use crate::escrow::{refund_creator, Escrow};
+
+/// Process AcceptApplication instruction
+///
+/// Creator picks an application and assigns the applicant as claimer. If the
+/// quote is below the bounty, the bounty is lowered to the quote and the
+/// difference is refunded: contributors' pro-rata part goes to the refund pool,
+/// the rest to the creator. The application's bond moves into the Task PDA and
+/// the application is closed, returning its rent to the applicant.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Creator wallet
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Task PDA
+/// 3. `[writable]` Application PDA (closed)
+/// 4. `[writable]` Applicant wallet (receives application rent)
+/// 5. `[]` System program
+///
+/// Token bounties only:
+/// 6. `[]` Mint
+/// 7. `[writable]` Escrow token account
+/// 8. `[]` Token program
+/// 9. `[]` Associated token program
+/// 10. `[writable]` Creator token account (ATA, created if missing)
+pub fn process_accept_application(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [
+        creator_info,
+        config_info,
+        task_info,
+        application_info,
+        applicant_info,
+        system_program,
+        token_accounts @ ..,
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let _args = bytemuck::try_from_bytes::<AcceptApplicationArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    creator_info.is_signer()?;
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Task must be open and in application mode
+    if task.status != STATUS_OPEN {
+        return Err(BountyBoardError::TaskNotOpen.into());
+    }
+    if !task.requires_application() {
+        return Err(BountyBoardError::ApplicationsClosed.into());
+    }
+
+    // Only creator can accept
+    if task.creator != *creator_info.key {
+        return Err(BountyBoardError::NotCreator.into());
+    }
+
+    // Verify Application PDA belongs to this task and applicant
+    let (application_pda_key, _bump) = application_pda(task.id, applicant_info.key);
+    if *application_info.key != application_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let application = *application_info.as_account::<Application>(&bountyboard_api::ID)?;
+
+    // Milestone amounts must cover the whole bounty before work starts,
+    // so milestone tasks only accept quotes for the full bounty
+    if task.has_milestones() {
+        if task.milestones_total != task.bounty {
+            return Err(BountyBoardError::MilestonesIncomplete.into());
+        }
+        if application.price != task.remaining() {
+            return Err(BountyBoardError::InvalidQuote.into());
+        }
+    }
+    if application.price > task.remaining() {
+        return Err(BountyBoardError::InvalidQuote.into());
+    }
+
+    // Check deadline hasn't passed (if set)
+    let clock = Clock::get()?;
+    if task.deadline > 0 && clock.unix_timestamp > task.deadline {
+        return Err(BountyBoardError::DeadlinePassed.into());
+    }
+
+    // Refund the difference between the bounty and the accepted quote
+    let refund = task
+        .remaining()
+        .checked_sub(application.price)
+        .ok_or(BountyBoardError::Overflow)?;
+    let mut escrow = Escrow::load(task_info, task, creator_info, system_program, token_accounts)?;
+    if refund > 0 {
+        let pooled = task
+            .contributors_share(refund)
+            .ok_or(BountyBoardError::Overflow)?;
+        refund_creator(&mut escrow, task, creator_info, refund)?;
+
+        task.bounty = task
+            .bounty
+            .checked_sub(refund)
+            .ok_or(BountyBoardError::Overflow)?;
+        task.contributed = task
+            .contributed
+            .saturating_sub(pooled)
+            .min(task.bounty);
+        if !task.is_token() {
+            config.total_escrowed = config
+                .total_escrowed
+                .checked_sub(refund)
+                .ok_or(BountyBoardError::Overflow)?;
+        }
+    }
+
+    // Move the applicant's bond into the task and close the application
+    application_info.send(application.bond, task_info);
+    application_info.close(applicant_info)?;
+
+    // Assign the claimer
+    task.claimer = application.applicant;
+    task.claimed_at = clock.unix_timestamp;
+    task.status = STATUS_CLAIMED;
+
+    solana_program::msg!(
+        "BountyBoard: Task {} assigned to {} at {}",
+        task.id,
+        application.applicant,
+        task.bounty
+    );
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::AddArbiterArgs,
+    state::{Arbiter, Config},
+};
+
+/// Process AddArbiter instruction
+///
+/// Admin registers an arbiter at the end of the registry.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Admin wallet
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Arbiter PDA at index `arbiter_count` (to be created)
+/// 3. `[]` System program
+pub fn process_add_arbiter(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [admin_info, config_info, arbiter_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<AddArbiterArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    admin_info.is_signer()?;
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+
+    // Verify admin
+    if config.admin != *admin_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    let index = config.arbiter_count;
+    let (arbiter_pda_key, _bump) = arbiter_pda(index);
+    if *arbiter_info.key != arbiter_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    create_program_account::<Arbiter>(
+        arbiter_info,
+        system_program,
+        admin_info,
+        &bountyboard_api::ID,
+        &[ARBITER, &index.to_le_bytes()],
+    )?;
+
+    let arbiter = arbiter_info.as_account_mut::<Arbiter>(&bountyboard_api::ID)?;
+    arbiter.wallet = args.wallet();
+    arbiter.index = index;
+    arbiter.added_at = Clock::get()?.unix_timestamp;
+
+    config.arbiter_count = index.checked_add(1).ok_or(BountyBoardError::Overflow)?;
+
+    solana_program::msg!("BountyBoard: Arbiter {} added at index {}", arbiter.wallet, index);
+
+    Ok(())
+}
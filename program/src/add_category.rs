@@ -0,0 +1,70 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::AddCategoryArgs,
+    state::{Category, Config},
+};
+
+/// Process AddCategory instruction
+///
+/// Admin registers a task category. New categories are active.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Admin wallet
+/// 1. `[]` Config PDA
+/// 2. `[writable]` Category PDA (to be created)
+/// 3. `[]` System program
+pub fn process_add_category(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [admin_info, config_info, category_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<AddCategoryArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let id = args.id();
+
+    admin_info.is_signer()?;
+
+    let config = config_info.as_account::<Config>(&bountyboard_api::ID)?;
+
+    // Verify admin
+    if config.admin != *admin_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    // 0 means uncategorized
+    if id == 0 {
+        return Err(BountyBoardError::InvalidCategory.into());
+    }
+
+    let (category_pda_key, _bump) = category_pda(id);
+    if *category_info.key != category_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    create_program_account::<Category>(
+        category_info,
+        system_program,
+        admin_info,
+        &bountyboard_api::ID,
+        &[CATEGORY, &id.to_le_bytes()],
+    )?;
+
+    let category = category_info.as_account_mut::<Category>(&bountyboard_api::ID)?;
+    category.id = id;
+    category.active = 1;
+    category.name = args.name;
+    category.created_at = Clock::get()?.unix_timestamp;
+
+    solana_program::msg!("BountyBoard: Category {} added", id);
+
+    Ok(())
+}
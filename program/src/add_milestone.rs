@@ -0,0 +1,112 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::AddMilestoneArgs,
+    state::{Milestone, Task},
+};
+
+/// Process AddMilestone instruction
+///
+/// Creator splits an open task's bounty into tranches. Milestones are appended in
+/// order and their amounts must add up to the bounty before the task can be claimed.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Creator wallet
+/// 1. `[writable]` Task PDA
+/// 2. `[writable]` Milestone PDA (to be created)
+/// 3. `[]` System program
+pub fn process_add_milestone(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [creator_info, task_info, milestone_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<AddMilestoneArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    creator_info.is_signer()?;
+
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Must be open
+    if task.status != STATUS_OPEN {
+        return Err(BountyBoardError::TaskNotOpen.into());
+    }
+
+    // Only creator can add milestones
+    if task.creator != *creator_info.key {
+        return Err(BountyBoardError::NotCreator.into());
+    }
+
+    // Contests pay out through AwardContest
+    if task.is_contest() {
+        return Err(BountyBoardError::ContestMode.into());
+    }
+
+    // Multi-slot tasks pay out per slot
+    if task.has_slots() {
+        return Err(BountyBoardError::HasSlots.into());
+    }
+
+    // Milestones are fixed once any tranche has been paid out
+    if task.released > 0 {
+        return Err(BountyBoardError::InvalidMilestone.into());
+    }
+
+    let amount = args.amount();
+    if amount == 0 {
+        return Err(BountyBoardError::BountyTooSmall.into());
+    }
+
+    let index = task.milestone_count;
+    if index >= MAX_MILESTONES {
+        return Err(BountyBoardError::MilestoneLimitExceeded.into());
+    }
+
+    let milestones_total = task
+        .milestones_total
+        .checked_add(amount)
+        .ok_or(BountyBoardError::Overflow)?;
+    if milestones_total > task.bounty {
+        return Err(BountyBoardError::MilestoneLimitExceeded.into());
+    }
+
+    // Verify milestone PDA
+    let (milestone_pda_key, _milestone_bump) = milestone_pda(task.id, index);
+    if *milestone_info.key != milestone_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Create Milestone PDA
+    create_program_account::<Milestone>(
+        milestone_info,
+        system_program,
+        creator_info,
+        &bountyboard_api::ID,
+        &[MILESTONE, &task.id.to_le_bytes(), &[index]],
+    )?;
+
+    let milestone = milestone_info.as_account_mut::<Milestone>(&bountyboard_api::ID)?;
+    milestone.task_id = task.id;
+    milestone.amount = amount;
+    milestone.description_hash = args.description_hash;
+    milestone.index = index;
+    milestone.status = MILESTONE_PENDING;
+
+    task.milestone_count = index + 1;
+    task.milestones_total = milestones_total;
+
+    solana_program::msg!(
+        "BountyBoard: Task {} milestone {} added with amount {}",
+        task.id,
+        index,
+        amount
+    );
+
+    Ok(())
+}
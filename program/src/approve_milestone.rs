@@ -0,0 +1,177 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::ApproveMilestoneArgs,
+    state::{Config, Milestone, Task},
+};
+
+use crate::{escrow::Escrow, reputation::record_payout};
+
+/// Process ApproveMilestone instruction
+///
+/// Creator approves a submitted milestone. Its tranche is released:
+/// - (amount - fee) to claimer
+/// - fee to treasury PDA
+///
+/// The task returns to CLAIMED for the next milestone, or COMPLETED after the last.
+///
+/// Accounts:
+/// 0. `[signer]` Creator wallet
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Task PDA
+/// 3. `[writable]` Milestone PDA
+/// 4. `[writable]` Claimer wallet (receives payment and claim bond)
+/// 5. `[writable]` Treasury PDA (receives fee)
+/// 6. `[]` System program
+/// 7. `[writable]` Creator Agent PDA (skipped if unregistered)
+/// 8. `[writable]` Claimer Agent PDA (skipped if unregistered)
+///
+/// Token bounties only (same trailing layout as ApproveWork):
+/// 9.. Mint, escrow token account, token program, associated token program,
+///     claimer token account, treasury token account
+pub fn process_approve_milestone(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [creator_info, _config_info, task_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<ApproveMilestoneArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    creator_info.is_signer()?;
+
+    // Only creator can approve
+    let task = task_info.as_account::<Task>(&bountyboard_api::ID)?;
+    if task.creator != *creator_info.key {
+        return Err(BountyBoardError::NotCreator.into());
+    }
+
+    pay_milestone(accounts, args.index)
+}
+
+/// Pays out a submitted milestone; shared by ApproveMilestone and
+/// ClaimExpiredMilestone, whose accounts follow the ApproveMilestone layout (with
+/// the caller, who pays for missing token accounts, in place of the creator).
+pub fn pay_milestone(accounts: &[AccountInfo], index: u8) -> ProgramResult {
+    let [
+        payer_info,
+        config_info,
+        task_info,
+        milestone_info,
+        claimer_info,
+        treasury_info,
+        system_program,
+        creator_agent_info,
+        claimer_agent_info,
+        token_accounts @ ..,
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Must be submitted
+    if task.status != STATUS_SUBMITTED {
+        return Err(BountyBoardError::InvalidTaskStatus.into());
+    }
+
+    // Verify claimer account matches task's claimer
+    if task.claimer != *claimer_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Verify treasury
+    if config.treasury != *treasury_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Verify milestone PDA
+    let (milestone_pda_key, _milestone_bump) = milestone_pda(task.id, index);
+    if *milestone_info.key != milestone_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let milestone = milestone_info.as_account_mut::<Milestone>(&bountyboard_api::ID)?;
+    if milestone.status != MILESTONE_SUBMITTED {
+        return Err(BountyBoardError::InvalidMilestone.into());
+    }
+
+    // Calculate fee and payment for this tranche
+    let amount = milestone.amount;
+    let fee = amount
+        .checked_mul(config.protocol_fee_bps as u64)
+        .ok_or(BountyBoardError::Overflow)?
+        .checked_div(TOTAL_BPS)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    let payment = amount
+        .checked_sub(fee)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    let mut escrow = Escrow::load(task_info, task, payer_info, system_program, token_accounts)?;
+    let claimer_destination = escrow.recipient(claimer_info)?;
+    let treasury_destination = escrow.recipient(treasury_info)?;
+
+    // Transfer payment from escrow to claimer
+    escrow.release(claimer_destination, payment)?;
+
+    // Transfer fee from escrow to treasury
+    escrow.release(treasury_destination, fee)?;
+
+    // Update milestone and task progress
+    milestone.status = MILESTONE_RELEASED;
+    task.released = task
+        .released
+        .checked_add(amount)
+        .ok_or(BountyBoardError::Overflow)?;
+    task.milestones_released = task
+        .milestones_released
+        .checked_add(1)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    if task.milestones_released == task.milestone_count {
+        task.status = STATUS_COMPLETED;
+
+        // Return the claim bond once the last tranche is paid
+        task_info.send(task.claim_bond, claimer_info);
+
+        config.total_completed = config
+            .total_completed
+            .checked_add(1)
+            .ok_or(BountyBoardError::Overflow)?;
+    } else {
+        task.status = STATUS_CLAIMED;
+    }
+
+    // Update config stats
+    if !task.is_token() {
+        config.total_escrowed = config
+            .total_escrowed
+            .checked_sub(amount)
+            .ok_or(BountyBoardError::Overflow)?;
+        config.total_fees_collected = config
+            .total_fees_collected
+            .checked_add(fee)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    // Update creator and claimer reputation
+    record_payout(creator_agent_info, claimer_agent_info, task, amount, payment)?;
+
+    solana_program::msg!(
+        "BountyBoard: Task {} milestone {} approved. Payment: {}, Fee: {}",
+        task.id,
+        index,
+        payment,
+        fee
+    );
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::ApproveSlotArgs,
+    state::{Config, Slot, Task},
+};
+
+use crate::{escrow::Escrow, reputation::record_payout};
+
+/// Process ApproveSlot instruction
+///
+/// Creator approves a submitted slot. Its share of the escrow is released:
+/// - (bounty_per_slot - fee) to the slot's claimer
+/// - fee to treasury PDA
+/// - claim bond back to the claimer
+///
+/// The Slot PDA is closed (rent back to the claimer). The task is COMPLETED once
+/// every slot has been paid out.
+///
+/// Accounts:
+/// 0. `[signer]` Creator wallet
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Task PDA
+/// 3. `[writable]` Slot PDA (closed)
+/// 4. `[writable]` Slot claimer wallet (receives payment, claim bond and slot rent)
+/// 5. `[writable]` Treasury PDA (receives fee)
+/// 6. `[]` System program
+/// 7. `[writable]` Creator Agent PDA (skipped if unregistered)
+/// 8. `[writable]` Claimer Agent PDA (skipped if unregistered)
+///
+/// Token bounties only (same trailing layout as ApproveWork):
+/// 9.. Mint, escrow token account, token program, associated token program,
+///     claimer token account, treasury token account
+pub fn process_approve_slot(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [creator_info, _config_info, task_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<ApproveSlotArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    creator_info.is_signer()?;
+
+    // Only creator can approve
+    let task = task_info.as_account::<Task>(&bountyboard_api::ID)?;
+    if task.creator != *creator_info.key {
+        return Err(BountyBoardError::NotCreator.into());
+    }
+
+    pay_slot(accounts, args.index())
+}
+
+/// Pays out a submitted slot; shared by ApproveSlot and ClaimExpiredSlot, whose
+/// accounts follow the ApproveSlot layout (with the caller, who pays for missing
+/// token accounts, in place of the creator).
+pub fn pay_slot(accounts: &[AccountInfo], index: u32) -> ProgramResult {
+    let [
+        payer_info,
+        config_info,
+        task_info,
+        slot_info,
+        claimer_info,
+        treasury_info,
+        system_program,
+        creator_agent_info,
+        claimer_agent_info,
+        token_accounts @ ..,
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    if task.status != STATUS_OPEN {
+        return Err(BountyBoardError::InvalidTaskStatus.into());
+    }
+
+    // Verify treasury
+    if config.treasury != *treasury_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Verify Slot PDA
+    let (slot_pda_key, _bump) = slot_pda(task.id, index);
+    if *slot_info.key != slot_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let slot = *slot_info.as_account::<Slot>(&bountyboard_api::ID)?;
+    if slot.status != SLOT_SUBMITTED {
+        return Err(BountyBoardError::InvalidSlot.into());
+    }
+
+    // Verify claimer account matches the slot's claimer
+    if slot.claimer != *claimer_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Calculate fee and payment for this slot
+    let amount = task.bounty_per_slot;
+    let fee = amount
+        .checked_mul(config.protocol_fee_bps as u64)
+        .ok_or(BountyBoardError::Overflow)?
+        .checked_div(TOTAL_BPS)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    let payment = amount
+        .checked_sub(fee)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    let mut escrow = Escrow::load(task_info, task, payer_info, system_program, token_accounts)?;
+    let claimer_destination = escrow.recipient(claimer_info)?;
+    let treasury_destination = escrow.recipient(treasury_info)?;
+
+    // Transfer payment from escrow to claimer
+    escrow.release(claimer_destination, payment)?;
+
+    // Transfer fee from escrow to treasury
+    escrow.release(treasury_destination, fee)?;
+
+    // Return the claim bond and the slot's rent
+    task_info.send(task.claim_bond, claimer_info);
+    slot_info.close(claimer_info)?;
+
+    // Update task progress
+    task.released = task
+        .released
+        .checked_add(amount)
+        .ok_or(BountyBoardError::Overflow)?;
+    task.slots_done = task
+        .slots_done
+        .checked_add(1)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    if task.slots_done == task.slots {
+        task.status = STATUS_COMPLETED;
+        config.total_completed = config
+            .total_completed
+            .checked_add(1)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    // Update config stats
+    if !task.is_token() {
+        config.total_escrowed = config
+            .total_escrowed
+            .checked_sub(amount)
+            .ok_or(BountyBoardError::Overflow)?;
+        config.total_fees_collected = config
+            .total_fees_collected
+            .checked_add(fee)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    // Update creator and slot claimer reputation; each slot counts as a completion
+    let mut slot_task = *task;
+    slot_task.claimer = slot.claimer;
+    slot_task.status = STATUS_COMPLETED;
+    record_payout(creator_agent_info, claimer_agent_info, &slot_task, amount, payment)?;
+
+    solana_program::msg!(
+        "BountyBoard: Task {} slot {} approved. Payment: {}, Fee: {} ({}/{} done)",
+        task.id,
+        slot.index,
+        payment,
+        fee,
+        task.slots_done,
+        task.slots
+    );
+
+    Ok(())
+}
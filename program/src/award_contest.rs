@@ -0,0 +1,195 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::AwardContestArgs,
+    state::{Config, Submission, Task},
+};
+
+use crate::escrow::Escrow;
+
+/// Process AwardContest instruction
+///
+/// After the submission deadline, and within CONTEST_AWARD_WINDOW of it, the
+/// creator splits the escrow across ranked winners by basis points
+/// (e.g. 6000/3000/1000). The protocol fee is taken from
+/// each payout, and rounding dust goes to the last rank. Winners' entries are
+/// closed and their rent returned. Agent reputation is not updated for contests.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Creator wallet
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Task PDA
+/// 3. `[writable]` Treasury PDA (receives fees)
+/// 4. `[]` System program
+///
+/// Then, for each winner in rank order:
+/// - `[writable]` Submission PDA (closed)
+/// - `[writable]` Winner wallet (receives payment and entry rent)
+///
+/// Token bounties only, after the winners:
+/// - `[]` Mint
+/// - `[writable]` Escrow token account
+/// - `[]` Token program
+/// - `[]` Associated token program
+/// - `[writable]` Treasury token account, then each winner's token account in rank
+///   order (ATAs, created if missing)
+pub fn process_award_contest(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [creator_info, config_info, task_info, treasury_info, system_program, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<AwardContestArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    creator_info.is_signer()?;
+
+    // Splits must cover the whole bounty, one non-zero share per rank
+    let winner_count = args.winner_count as usize;
+    if winner_count == 0 || winner_count > MAX_CONTEST_WINNERS {
+        return Err(BountyBoardError::InvalidSplits.into());
+    }
+    let mut total_bps = 0u64;
+    for rank in 0..winner_count {
+        let bps = args.split_bps(rank) as u64;
+        if bps == 0 {
+            return Err(BountyBoardError::InvalidSplits.into());
+        }
+        total_bps += bps;
+    }
+    if total_bps != TOTAL_BPS {
+        return Err(BountyBoardError::InvalidSplits.into());
+    }
+
+    if remaining.len() < winner_count * 2 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (winner_accounts, token_accounts) = remaining.split_at(winner_count * 2);
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Must be an open contest past its deadline
+    if !task.is_contest() {
+        return Err(BountyBoardError::NotContest.into());
+    }
+    if task.status != STATUS_OPEN {
+        return Err(BountyBoardError::InvalidTaskStatus.into());
+    }
+    let now = Clock::get()?.unix_timestamp;
+    if now <= task.deadline {
+        return Err(BountyBoardError::ContestOpen.into());
+    }
+    // Past the window the entrants split the bounty with ClaimContestShare
+    if now > task.deadline.saturating_add(CONTEST_AWARD_WINDOW) {
+        return Err(BountyBoardError::AwardWindowClosed.into());
+    }
+
+    // Only creator can award
+    if task.creator != *creator_info.key {
+        return Err(BountyBoardError::NotCreator.into());
+    }
+
+    // Verify treasury
+    if config.treasury != *treasury_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let bounty = task.remaining();
+    let mut escrow = Escrow::load(task_info, task, creator_info, system_program, token_accounts)?;
+    let treasury_destination = escrow.recipient(treasury_info)?;
+
+    let mut paid = 0u64;
+    let mut total_fee = 0u64;
+    for (rank, winner) in winner_accounts.chunks_exact(2).enumerate() {
+        let [submission_info, winner_info] = winner else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Each rank must be a distinct entrant of this contest
+        if winner_accounts[..rank * 2]
+            .chunks_exact(2)
+            .any(|previous| previous[1].key == winner_info.key)
+        {
+            return Err(BountyBoardError::InvalidSplits.into());
+        }
+        let (submission_pda_key, _bump) = submission_pda(task.id, winner_info.key);
+        if *submission_info.key != submission_pda_key {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        submission_info.as_account::<Submission>(&bountyboard_api::ID)?;
+
+        // Last rank takes the rounding dust
+        let amount = if rank + 1 == winner_count {
+            bounty.checked_sub(paid).ok_or(BountyBoardError::Overflow)?
+        } else {
+            bounty
+                .checked_mul(args.split_bps(rank) as u64)
+                .ok_or(BountyBoardError::Overflow)?
+                .checked_div(TOTAL_BPS)
+                .ok_or(BountyBoardError::Overflow)?
+        };
+        let fee = amount
+            .checked_mul(config.protocol_fee_bps as u64)
+            .ok_or(BountyBoardError::Overflow)?
+            .checked_div(TOTAL_BPS)
+            .ok_or(BountyBoardError::Overflow)?;
+        let payment = amount.checked_sub(fee).ok_or(BountyBoardError::Overflow)?;
+
+        let winner_destination = escrow.recipient(winner_info)?;
+        escrow.release(winner_destination, payment)?;
+
+        paid = paid.checked_add(amount).ok_or(BountyBoardError::Overflow)?;
+        total_fee = total_fee.checked_add(fee).ok_or(BountyBoardError::Overflow)?;
+
+        // Return the winner's entry rent
+        submission_info.close(winner_info)?;
+
+        solana_program::msg!(
+            "BountyBoard: Contest {} rank {} to {}: {}",
+            task.id,
+            rank + 1,
+            winner_info.key,
+            payment
+        );
+    }
+
+    // Fees to treasury
+    escrow.release(treasury_destination, total_fee)?;
+
+    // Top rank is recorded as the task's claimer
+    task.claimer = *winner_accounts[1].key;
+    task.status = STATUS_COMPLETED;
+
+    // Update config stats
+    if !task.is_token() {
+        config.total_escrowed = config
+            .total_escrowed
+            .checked_sub(bounty)
+            .ok_or(BountyBoardError::Overflow)?;
+        config.total_fees_collected = config
+            .total_fees_collected
+            .checked_add(total_fee)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+    config.total_completed = config
+        .total_completed
+        .checked_add(1)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    solana_program::msg!(
+        "BountyBoard: Contest {} awarded to {} winners. Fee: {}",
+        task.id,
+        winner_count,
+        total_fee
+    );
+
+    Ok(())
+}
@@ -11,17 +11,36 @@ use bountyboard_api::{
     state::{Config, Task},
 };
 
+use crate::{
+    dependencies::{release_prerequisites, split_prerequisites},
+    escrow::{refund_creator, Escrow},
+};
+
 /// Process CancelTask instruction
 ///
-/// Creator cancels an unclaimed (OPEN) task. Full bounty refund.
+/// Creator cancels an unclaimed (OPEN) task. Refunds the bounty, minus any
+/// milestones already released; contributors' share is kept for
+/// WithdrawContribution. Contests can only be cancelled before any entry.
+/// Multi-slot tasks refund their free slots; see `refund_open_task` for slots
+/// still in progress.
 ///
 /// Accounts:
 /// 0. `[signer, writable]` Creator wallet
 /// 1. `[writable]` Config PDA
 /// 2. `[writable]` Task PDA
 /// 3. `[]` System program
+///
+/// Token bounties only:
+/// 4. `[]` Mint
+/// 5. `[writable]` Escrow token account
+/// 6. `[]` Token program
+/// 7. `[]` Associated token program
+/// 8. `[writable]` Creator token account (ATA, created if missing)
+///
+/// Then, for unclaimed dependent tasks: `[writable]` prerequisite Task PDAs, in
+/// the order of `Task.dependencies`.
 pub fn process_cancel_task(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
-    let [creator_info, config_info, task_info, _system_program] = accounts else {
+    let [creator_info, config_info, task_info, system_program, remaining @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -43,22 +62,85 @@ pub fn process_cancel_task(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
         return Err(BountyBoardError::NotCreator.into());
     }
 
-    let bounty = task.bounty;
+    // Entrants have already done the work
+    if task.is_contest() && task.entries > 0 {
+        return Err(BountyBoardError::ContestHasEntries.into());
+    }
 
-    // Refund bounty from Task PDA to creator
-    **task_info.try_borrow_mut_lamports()? -= bounty;
-    **creator_info.try_borrow_mut_lamports()? += bounty;
+    // Let go of the prerequisites so they can be closed
+    let (token_accounts, dependency_infos) =
+        split_prerequisites(remaining, task.held_prerequisites().len())?;
+    release_prerequisites(task, dependency_infos)?;
 
-    // Update task status
-    task.status = STATUS_CANCELLED;
+    // Refund bounty from escrow to creator and contributors
+    let mut escrow = Escrow::load(task_info, task, creator_info, system_program, token_accounts)?;
+    let bounty = refund_open_task(&mut escrow, task, creator_info)?;
 
     // Update config
-    config.total_escrowed = config
-        .total_escrowed
-        .checked_sub(bounty)
-        .ok_or(BountyBoardError::Overflow)?;
+    if !task.is_token() {
+        config.total_escrowed = config
+            .total_escrowed
+            .checked_sub(bounty)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
 
-    solana_program::msg!("BountyBoard: Task {} cancelled, refunded {} lamports", task.id, bounty);
+    solana_program::msg!("BountyBoard: Task {} cancelled, refunded {}", task.id, bounty);
 
     Ok(())
 }
+
+/// Refunds what is left of an OPEN task's bounty and cancels it; shared by
+/// CancelTask and ExpireTask. Returns the amount refunded.
+///
+/// Multi-slot tasks only refund their free slots. If slots are still being worked
+/// on, the task shrinks to its taken slots and stays OPEN without accepting new
+/// claims: those slots can still be paid out, and any slot freed later is refunded
+/// by calling CancelTask / ExpireTask again.
+pub fn refund_open_task<'a, 'info>(
+    escrow: &mut Escrow<'a, 'info>,
+    task: &mut Task,
+    creator: &'a AccountInfo<'info>,
+) -> Result<u64, ProgramError> {
+    if !task.has_slots() {
+        let bounty = task.remaining();
+        refund_creator(escrow, task, creator, bounty)?;
+        task.status = STATUS_CANCELLED;
+        return Ok(bounty);
+    }
+
+    let free_slots = task
+        .slots
+        .checked_sub(task.slots_taken)
+        .ok_or(BountyBoardError::Overflow)?;
+    let refund = task
+        .bounty_per_slot
+        .checked_mul(free_slots as u64)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    // Already closed and no slot freed since: nothing to do until one is
+    if task.slots_closed != 0 && refund == 0 && task.slots_taken != task.slots_done {
+        return Err(BountyBoardError::TaskAlreadyClaimed.into());
+    }
+
+    let pooled = task
+        .contributors_share(refund)
+        .ok_or(BountyBoardError::Overflow)?;
+    refund_creator(escrow, task, creator, refund)?;
+
+    task.slots = task.slots_taken;
+    task.bounty = task
+        .bounty
+        .checked_sub(refund)
+        .ok_or(BountyBoardError::Overflow)?;
+    task.contributed = task
+        .contributed
+        .saturating_sub(pooled)
+        .min(task.bounty);
+    if task.slots_taken == task.slots_done {
+        task.status = STATUS_CANCELLED;
+    } else {
+        task.slots_closed = 1;
+    }
+
+    Ok(refund)
+}
@@ -0,0 +1,73 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::CastVoteArgs,
+    state::Dispute,
+};
+
+/// Process CastVote instruction
+///
+/// An arbiter on the dispute's panel votes for the creator or the claimer before
+/// the voting deadline. Each arbiter votes once; arbiters who are a party to the
+/// task cannot vote.
+///
+/// Accounts:
+/// 0. `[signer]` Arbiter wallet
+/// 1. `[writable]` Dispute PDA
+pub fn process_cast_vote(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [arbiter_info, dispute_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<CastVoteArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    arbiter_info.is_signer()?;
+
+    if args.vote != VOTE_CREATOR && args.vote != VOTE_CLAIMER {
+        return Err(BountyBoardError::InvalidDisputeWinner.into());
+    }
+
+    // Verify Dispute PDA
+    if *dispute_info.key != dispute_pda(args.task_id()).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let dispute = dispute_info.as_account_mut::<Dispute>(&bountyboard_api::ID)?;
+
+    if Clock::get()?.unix_timestamp > dispute.voting_deadline {
+        return Err(BountyBoardError::VotingClosed.into());
+    }
+
+    // Parties to the task cannot judge it
+    if *arbiter_info.key == dispute.creator || *arbiter_info.key == dispute.claimer {
+        return Err(BountyBoardError::NotArbiter.into());
+    }
+
+    let seat = dispute.arbiters[..dispute.arbiter_count as usize]
+        .iter()
+        .position(|arbiter| arbiter == arbiter_info.key)
+        .ok_or(BountyBoardError::NotArbiter)?;
+    if dispute.votes[seat] != VOTE_NONE {
+        return Err(BountyBoardError::AlreadyVoted.into());
+    }
+    dispute.votes[seat] = args.vote;
+
+    let (creator_votes, claimer_votes) = dispute.tally();
+    solana_program::msg!(
+        "BountyBoard: Arbiter {} voted on task {} ({} creator / {} claimer)",
+        arbiter_info.key,
+        dispute.task_id,
+        creator_votes,
+        claimer_votes
+    );
+
+    Ok(())
+}
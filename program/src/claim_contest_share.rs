@@ -0,0 +1,146 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::ClaimContestShareArgs,
+    state::{Config, Submission, Task},
+};
+
+use crate::escrow::Escrow;
+
+/// Process ClaimContestShare instruction
+///
+/// Permissionless: once a contest with entries is still unawarded
+/// CONTEST_AWARD_WINDOW after its deadline, its bounty is split equally among
+/// the entrants. Each call pays one entrant its share of what is left (the
+/// protocol fee is taken as with AwardContest) and closes its entry; the last
+/// entrant paid takes the rounding dust and completes the task.
+///
+/// Accounts:
+/// 0. `[signer]` Caller (anyone — pays for missing token accounts)
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Task PDA
+/// 3. `[writable]` Treasury PDA (receives fee)
+/// 4. `[]` System program
+/// 5. `[writable]` Submission PDA (closed)
+/// 6. `[writable]` Entrant wallet (receives share and entry rent)
+///
+/// Token bounties only:
+/// 7. `[]` Mint
+/// 8. `[writable]` Escrow token account
+/// 9. `[]` Token program
+/// 10. `[]` Associated token program
+/// 11. `[writable]` Entrant token account (ATA, created if missing)
+/// 12. `[writable]` Treasury token account (ATA, created if missing)
+pub fn process_claim_contest_share(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [
+        caller_info,
+        config_info,
+        task_info,
+        treasury_info,
+        system_program,
+        submission_info,
+        entrant_info,
+        token_accounts @ ..,
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let _args = bytemuck::try_from_bytes::<ClaimContestShareArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    caller_info.is_signer()?;
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Must be an open contest with entries left to pay
+    if !task.is_contest() {
+        return Err(BountyBoardError::NotContest.into());
+    }
+    if task.status != STATUS_OPEN || task.entries == 0 {
+        return Err(BountyBoardError::InvalidTaskStatus.into());
+    }
+
+    // The creator keeps the award window to pick winners
+    let now = Clock::get()?.unix_timestamp;
+    if now <= task.deadline.saturating_add(CONTEST_AWARD_WINDOW) {
+        return Err(BountyBoardError::AwardWindowOpen.into());
+    }
+
+    // Verify treasury
+    if config.treasury != *treasury_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Verify Submission PDA
+    let (submission_pda_key, _bump) = submission_pda(task.id, entrant_info.key);
+    if *submission_info.key != submission_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    submission_info.as_account::<Submission>(&bountyboard_api::ID)?;
+
+    // Equal split of what is left; the last entrant takes the rounding dust
+    let share = if task.entries == 1 {
+        task.remaining()
+    } else {
+        task.remaining()
+            .checked_div(task.entries)
+            .ok_or(BountyBoardError::Overflow)?
+    };
+    let fee = share
+        .checked_mul(config.protocol_fee_bps as u64)
+        .ok_or(BountyBoardError::Overflow)?
+        .checked_div(TOTAL_BPS)
+        .ok_or(BountyBoardError::Overflow)?;
+    let payment = share.checked_sub(fee).ok_or(BountyBoardError::Overflow)?;
+
+    let mut escrow = Escrow::load(task_info, task, caller_info, system_program, token_accounts)?;
+    let entrant_destination = escrow.recipient(entrant_info)?;
+    let treasury_destination = escrow.recipient(treasury_info)?;
+    escrow.release(entrant_destination, payment)?;
+    escrow.release(treasury_destination, fee)?;
+
+    // Return the entry's rent
+    submission_info.close(entrant_info)?;
+
+    task.released = task.released.checked_add(share).ok_or(BountyBoardError::Overflow)?;
+    task.entries = task.entries.checked_sub(1).ok_or(BountyBoardError::Overflow)?;
+    if task.entries == 0 {
+        task.status = STATUS_COMPLETED;
+        config.total_completed = config
+            .total_completed
+            .checked_add(1)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    // Update config stats
+    if !task.is_token() {
+        config.total_escrowed = config
+            .total_escrowed
+            .checked_sub(share)
+            .ok_or(BountyBoardError::Overflow)?;
+        config.total_fees_collected = config
+            .total_fees_collected
+            .checked_add(fee)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    solana_program::msg!(
+        "BountyBoard: Contest {} share to {}: {}. Fee: {}",
+        task.id,
+        entrant_info.key,
+        payment,
+        fee
+    );
+
+    Ok(())
+}
@@ -13,6 +13,8 @@ use bountyboard_api::{
     state::{Config, Task},
 };
 
+use crate::{escrow::Escrow, reputation::record_payout};
+
 /// Process ClaimExpired instruction
 ///
 /// Permissionless auto-release: if a task has been in SUBMITTED status for more
@@ -23,11 +25,32 @@ use bountyboard_api::{
 /// 0. `[signer]` Caller (anyone — permissionless)
 /// 1. `[writable]` Config PDA
 /// 2. `[writable]` Task PDA
-/// 3. `[writable]` Claimer wallet (receives payment)
+/// 3. `[writable]` Claimer wallet (receives payment and claim bond)
 /// 4. `[writable]` Treasury PDA (receives fee)
 /// 5. `[]` System program
+/// 6. `[writable]` Creator Agent PDA (skipped if unregistered)
+/// 7. `[writable]` Claimer Agent PDA (skipped if unregistered)
+///
+/// Token bounties only (same layout as ApproveWork):
+/// 8. `[]` Mint
+/// 9. `[writable]` Escrow token account
+/// 10. `[]` Token program
+/// 11. `[]` Associated token program
+/// 12. `[writable]` Claimer token account (ATA, created if missing)
+/// 13. `[writable]` Treasury token account (ATA, created if missing)
 pub fn process_claim_expired(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
-    let [caller_info, config_info, task_info, claimer_info, treasury_info, _system_program] = accounts else {
+    let [
+        caller_info,
+        config_info,
+        task_info,
+        claimer_info,
+        treasury_info,
+        system_program,
+        creator_agent_info,
+        claimer_agent_info,
+        token_accounts @ ..,
+    ] = accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -44,6 +67,11 @@ pub fn process_claim_expired(accounts: &[AccountInfo], data: &[u8]) -> ProgramRe
         return Err(BountyBoardError::InvalidTaskStatus.into());
     }
 
+    // Milestone tasks are released per milestone (ClaimExpiredMilestone)
+    if task.has_milestones() {
+        return Err(BountyBoardError::HasMilestones.into());
+    }
+
     // Verify claimer account matches task's claimer
     if task.claimer != *claimer_info.key {
         return Err(ProgramError::InvalidAccountData);
@@ -80,29 +108,43 @@ pub fn process_claim_expired(accounts: &[AccountInfo], data: &[u8]) -> ProgramRe
         .checked_sub(fee)
         .ok_or(BountyBoardError::Overflow)?;
 
-    // Transfer payment from Task PDA to claimer
-    **task_info.try_borrow_mut_lamports()? -= payment;
-    **claimer_info.try_borrow_mut_lamports()? += payment;
+    let mut escrow = Escrow::load(task_info, task, caller_info, system_program, token_accounts)?;
+    let claimer_destination = escrow.recipient(claimer_info)?;
+    let treasury_destination = escrow.recipient(treasury_info)?;
+
+    // Transfer payment from escrow to claimer
+    escrow.release(claimer_destination, payment)?;
 
-    // Transfer fee from Task PDA to treasury
-    **task_info.try_borrow_mut_lamports()? -= fee;
-    **treasury_info.try_borrow_mut_lamports()? += fee;
+    // Transfer fee from escrow to treasury
+    escrow.release(treasury_destination, fee)?;
+
+    // Return the claim bond
+    task_info.send(task.claim_bond, claimer_info);
 
     // Update task status to completed
     task.status = STATUS_COMPLETED;
 
     // Update config stats
-    config.total_escrowed = config
-        .total_escrowed
-        .checked_sub(task.bounty)
-        .ok_or(BountyBoardError::Overflow)?;
+    if !task.is_token() {
+        config.total_escrowed = config
+            .total_escrowed
+            .checked_sub(task.bounty)
+            .ok_or(BountyBoardError::Overflow)?;
+        config.total_fees_collected = config
+            .total_fees_collected
+            .checked_add(fee)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
     config.total_completed = config
         .total_completed
         .checked_add(1)
         .ok_or(BountyBoardError::Overflow)?;
 
+    // Update creator and claimer reputation
+    record_payout(creator_agent_info, claimer_agent_info, task, task.bounty, payment)?;
+
     solana_program::msg!(
-        "BountyBoard: Task {} auto-released after {}s timeout. Payment: {} lamports to worker",
+        "BountyBoard: Task {} auto-released after {}s timeout. Payment: {} to worker",
         task.id,
         elapsed,
         payment
@@ -0,0 +1,77 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::ClaimExpiredMilestoneArgs,
+    state::{Milestone, Task},
+};
+
+use crate::approve_milestone::pay_milestone;
+
+/// Process ClaimExpiredMilestone instruction
+///
+/// Permissionless auto-release for milestones: if a milestone has been SUBMITTED
+/// for more than AUTO_RELEASE_TIMEOUT (48h) without the creator approving or
+/// rejecting it, anyone can release its tranche exactly as ApproveMilestone would.
+///
+/// Accounts (same layout as ApproveMilestone):
+/// 0. `[signer]` Caller (anyone — pays for missing token accounts)
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Task PDA
+/// 3. `[writable]` Milestone PDA
+/// 4. `[writable]` Claimer wallet (receives payment and claim bond)
+/// 5. `[writable]` Treasury PDA (receives fee)
+/// 6. `[]` System program
+/// 7. `[writable]` Creator Agent PDA (skipped if unregistered)
+/// 8. `[writable]` Claimer Agent PDA (skipped if unregistered)
+///
+/// Token bounties only (same trailing layout as ApproveWork):
+/// 9.. Mint, escrow token account, token program, associated token program,
+///     claimer token account, treasury token account
+pub fn process_claim_expired_milestone(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [caller_info, _config_info, task_info, milestone_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<ClaimExpiredMilestoneArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    caller_info.is_signer()?;
+
+    let task = task_info.as_account::<Task>(&bountyboard_api::ID)?;
+
+    // Verify milestone PDA
+    let (milestone_pda_key, _milestone_bump) = milestone_pda(task.id, args.index);
+    if *milestone_info.key != milestone_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let milestone = milestone_info.as_account::<Milestone>(&bountyboard_api::ID)?;
+    if milestone.status != MILESTONE_SUBMITTED || milestone.submitted_at == 0 {
+        return Err(BountyBoardError::InvalidMilestone.into());
+    }
+
+    let elapsed = Clock::get()?
+        .unix_timestamp
+        .checked_sub(milestone.submitted_at)
+        .ok_or(BountyBoardError::Overflow)?;
+    if elapsed < AUTO_RELEASE_TIMEOUT {
+        return Err(BountyBoardError::AutoReleaseNotReady.into());
+    }
+
+    solana_program::msg!(
+        "BountyBoard: Task {} milestone {} auto-released after {}s timeout",
+        task.id,
+        args.index,
+        elapsed
+    );
+
+    pay_milestone(accounts, args.index)
+}
@@ -0,0 +1,77 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::ClaimExpiredSlotArgs,
+    state::{Slot, Task},
+};
+
+use crate::approve_slot::pay_slot;
+
+/// Process ClaimExpiredSlot instruction
+///
+/// Permissionless auto-release for slots: if a slot has been SUBMITTED for more
+/// than AUTO_RELEASE_TIMEOUT (48h) without the creator approving or rejecting it,
+/// anyone can pay it out exactly as ApproveSlot would.
+///
+/// Accounts (same layout as ApproveSlot):
+/// 0. `[signer]` Caller (anyone — pays for missing token accounts)
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Task PDA
+/// 3. `[writable]` Slot PDA (closed)
+/// 4. `[writable]` Slot claimer wallet (receives payment, claim bond and slot rent)
+/// 5. `[writable]` Treasury PDA (receives fee)
+/// 6. `[]` System program
+/// 7. `[writable]` Creator Agent PDA (skipped if unregistered)
+/// 8. `[writable]` Claimer Agent PDA (skipped if unregistered)
+///
+/// Token bounties only (same trailing layout as ApproveWork):
+/// 9.. Mint, escrow token account, token program, associated token program,
+///     claimer token account, treasury token account
+pub fn process_claim_expired_slot(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [caller_info, _config_info, task_info, slot_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<ClaimExpiredSlotArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    caller_info.is_signer()?;
+
+    let task = task_info.as_account::<Task>(&bountyboard_api::ID)?;
+
+    // Verify Slot PDA
+    let (slot_pda_key, _bump) = slot_pda(task.id, args.index());
+    if *slot_info.key != slot_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let slot = slot_info.as_account::<Slot>(&bountyboard_api::ID)?;
+    if slot.status != SLOT_SUBMITTED {
+        return Err(BountyBoardError::InvalidSlot.into());
+    }
+
+    let elapsed = Clock::get()?
+        .unix_timestamp
+        .checked_sub(slot.submitted_at)
+        .ok_or(BountyBoardError::Overflow)?;
+    if elapsed < AUTO_RELEASE_TIMEOUT {
+        return Err(BountyBoardError::AutoReleaseNotReady.into());
+    }
+
+    solana_program::msg!(
+        "BountyBoard: Task {} slot {} auto-released after {}s timeout",
+        task.id,
+        slot.index,
+        elapsed
+    );
+
+    pay_slot(accounts, args.index())
+}
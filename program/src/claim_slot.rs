@@ -0,0 +1,101 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::ClaimSlotArgs,
+    state::{Slot, Task},
+};
+
+/// Process ClaimSlot instruction
+///
+/// Worker claims the next free slot of a multi-slot task. The Slot PDA is created
+/// at the task's `next_slot` index and the claim bond (if any) is escrowed into the
+/// Task PDA.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Claimer wallet
+/// 1. `[writable]` Task PDA
+/// 2. `[writable]` Slot PDA (to be created)
+/// 3. `[]` System program
+pub fn process_claim_slot(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [claimer_info, task_info, slot_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let _args = bytemuck::try_from_bytes::<ClaimSlotArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    claimer_info.is_signer()?;
+
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Must be an open multi-slot task with a free slot
+    if !task.has_slots() {
+        return Err(BountyBoardError::InvalidSlot.into());
+    }
+    if task.status != STATUS_OPEN || task.slots_closed != 0 {
+        return Err(BountyBoardError::TaskNotOpen.into());
+    }
+    if task.slots_taken >= task.slots {
+        return Err(BountyBoardError::SlotsFull.into());
+    }
+
+    // No paying yourself (and farming reputation) from your own task
+    if task.creator == *claimer_info.key {
+        return Err(BountyBoardError::SelfClaim.into());
+    }
+
+    // Check deadline hasn't passed (if set)
+    let clock = Clock::get()?;
+    if task.deadline > 0 && clock.unix_timestamp > task.deadline {
+        return Err(BountyBoardError::DeadlinePassed.into());
+    }
+
+    // Verify Slot PDA
+    let index = task.next_slot;
+    let (slot_pda_key, _bump) = slot_pda(task.id, index);
+    if *slot_info.key != slot_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    create_program_account::<Slot>(
+        slot_info,
+        system_program,
+        claimer_info,
+        &bountyboard_api::ID,
+        &[SLOT, &task.id.to_le_bytes(), &index.to_le_bytes()],
+    )?;
+
+    let slot = slot_info.as_account_mut::<Slot>(&bountyboard_api::ID)?;
+    slot.task_id = task.id;
+    slot.claimer = *claimer_info.key;
+    slot.index = index;
+    slot.status = SLOT_CLAIMED;
+    slot.claimed_at = clock.unix_timestamp;
+
+    // Escrow the claim bond
+    if task.claim_bond > 0 {
+        task_info.collect(task.claim_bond, claimer_info)?;
+    }
+
+    task.next_slot = index.checked_add(1).ok_or(BountyBoardError::Overflow)?;
+    task.slots_taken = task.slots_taken.checked_add(1).ok_or(BountyBoardError::Overflow)?;
+
+    solana_program::msg!(
+        "BountyBoard: Task {} slot {} claimed by {} ({}/{})",
+        task.id,
+        index,
+        claimer_info.key,
+        task.slots_taken,
+        task.slots
+    );
+
+    Ok(())
+}
@@ -13,20 +13,32 @@ use bountyboard_api::{
     state::Task,
 };
 
+use crate::{
+    dependencies::{release_prerequisites, Prerequisites},
+    reputation::load_agent,
+};
+
 /// Process ClaimTask instruction
 ///
-/// Agent claims an open task. Records claimer and optional deadline.
+/// Agent claims an open task. Records claimer and optional deadline, and escrows
+/// the task's claim bond (if any) into the Task PDA. Tasks with prerequisites can
+/// only be claimed once every prerequisite task is COMPLETED.
 ///
 /// Accounts:
-/// 0. `[signer]` Claimer wallet
+/// 0. `[signer, writable]` Claimer wallet (pays the claim bond; must be the direct
+///    hire or prove allowlist membership on private tasks)
 /// 1. `[writable]` Task PDA
+/// 2. `[]` System program
+/// 3. `[]` Claimer Agent PDA (must be registered if the task has reputation requirements)
+///
+/// Followed, on a task's first claim, by its `[writable]` prerequisite Task PDAs in
+/// the order of `Task.dependencies`; the claim releases their hold on CloseTask.
 pub fn process_claim_task(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
-    let [claimer_info, task_info] = accounts else {
+    let [claimer_info, task_info, _system_program, claimer_agent_info, dependency_infos @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let _args = bytemuck::try_from_bytes::<ClaimTaskArgs>(data)
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let (_args, proof) = ClaimTaskArgs::parse(data).ok_or(BountyBoardError::InvalidProof)?;
 
     claimer_info.is_signer()?;
 
@@ -37,6 +49,52 @@ pub fn process_claim_task(accounts: &[AccountInfo], data: &[u8]) -> ProgramResul
         return Err(BountyBoardError::TaskNotOpen.into());
     }
 
+    // No paying yourself (and farming reputation) from your own task
+    if task.creator == *claimer_info.key {
+        return Err(BountyBoardError::SelfClaim.into());
+    }
+
+    // Multi-slot tasks are claimed per slot
+    if task.has_slots() {
+        return Err(BountyBoardError::HasSlots.into());
+    }
+
+    // Contest entries go through SubmitEntry
+    if task.is_contest() {
+        return Err(BountyBoardError::ContestMode.into());
+    }
+
+    // Application-mode tasks are assigned through AcceptApplication
+    if task.requires_application() {
+        return Err(BountyBoardError::ApplicationRequired.into());
+    }
+
+    // Private tasks: direct hire or allowlist proof
+    if !task.is_allowed_claimer(claimer_info.key, proof) {
+        return Err(BountyBoardError::NotAllowedClaimer.into());
+    }
+
+    // Reputation-gated tasks: minimum completions and approval rate
+    if task.has_reputation_gate() {
+        let claimer_agent = load_agent(claimer_agent_info, claimer_info.key)?
+            .ok_or(BountyBoardError::InsufficientReputation)?;
+        if claimer_agent.tasks_completed < task.min_completions as u64
+            || claimer_agent.approval_rate_bps() < task.min_approval_bps as u64
+        {
+            return Err(BountyBoardError::InsufficientReputation.into());
+        }
+    }
+
+    // Prerequisite tasks must all be completed
+    if release_prerequisites(task, dependency_infos)? != Prerequisites::Completed {
+        return Err(BountyBoardError::DependenciesIncomplete.into());
+    }
+
+    // Milestone amounts must cover the whole bounty before work starts
+    if task.has_milestones() && task.milestones_total != task.bounty {
+        return Err(BountyBoardError::MilestonesIncomplete.into());
+    }
+
     // Check deadline hasn't passed (if set)
     if task.deadline > 0 {
         let clock = Clock::get()?;
@@ -45,6 +103,11 @@ pub fn process_claim_task(accounts: &[AccountInfo], data: &[u8]) -> ProgramResul
         }
     }
 
+    // Escrow the claim bond
+    if task.claim_bond > 0 {
+        task_info.collect(task.claim_bond, claimer_info)?;
+    }
+
     // Claim the task
     task.claimer = *claimer_info.key;
     task.claimed_at = Clock::get()?.unix_timestamp;
@@ -0,0 +1,66 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::CloseApplicationArgs,
+    state::{Application, Task},
+};
+
+/// Process CloseApplication instruction
+///
+/// Closes a pending application, returning its rent and bond to the applicant.
+/// The applicant can withdraw at any time; anyone else can close it once the task
+/// is no longer open (another application was accepted, or the task was cancelled
+/// or closed).
+///
+/// Accounts:
+/// 0. `[signer]` Caller
+/// 1. `[]` Task PDA
+/// 2. `[writable]` Application PDA
+/// 3. `[writable]` Applicant wallet (receives rent and bond)
+pub fn process_close_application(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [caller_info, task_info, application_info, applicant_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<CloseApplicationArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let task_id = args.task_id();
+
+    caller_info.is_signer()?;
+
+    // Verify PDAs
+    if *task_info.key != task_pda(task_id).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let (application_pda_key, _bump) = application_pda(task_id, applicant_info.key);
+    if *application_info.key != application_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    application_info.as_account::<Application>(&bountyboard_api::ID)?;
+
+    // Others may only close once the task stopped taking applications
+    if caller_info.key != applicant_info.key {
+        let task_open = task_info
+            .as_account::<Task>(&bountyboard_api::ID)
+            .is_ok_and(|task| task.status == STATUS_OPEN);
+        if task_open {
+            return Err(BountyBoardError::Unauthorized.into());
+        }
+    }
+
+    application_info.close(applicant_info)?;
+
+    solana_program::msg!(
+        "BountyBoard: Application of {} to task {} closed",
+        applicant_info.key,
+        task_id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::CloseSubmissionArgs,
+    state::{Submission, Task},
+};
+
+/// Process CloseSubmission instruction
+///
+/// Permissionless: once a contest is no longer open (awarded, cancelled or
+/// closed), anyone can close an entry and return its rent to the entrant.
+///
+/// Accounts:
+/// 0. `[signer]` Caller (anyone — permissionless)
+/// 1. `[]` Task PDA
+/// 2. `[writable]` Submission PDA
+/// 3. `[writable]` Entrant wallet (receives rent)
+pub fn process_close_submission(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [caller_info, task_info, submission_info, entrant_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<CloseSubmissionArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let task_id = args.task_id();
+
+    caller_info.is_signer()?;
+
+    // Verify PDAs
+    if *task_info.key != task_pda(task_id).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let (submission_pda_key, _bump) = submission_pda(task_id, entrant_info.key);
+    if *submission_info.key != submission_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    submission_info.as_account::<Submission>(&bountyboard_api::ID)?;
+
+    // Entries stay until the contest is decided
+    let task_open = task_info
+        .as_account::<Task>(&bountyboard_api::ID)
+        .is_ok_and(|task| task.status == STATUS_OPEN);
+    if task_open {
+        return Err(BountyBoardError::ContestOpen.into());
+    }
+
+    submission_info.close(entrant_info)?;
+
+    solana_program::msg!(
+        "BountyBoard: Entry of {} to contest {} closed",
+        entrant_info.key,
+        task_id
+    );
+
+    Ok(())
+}
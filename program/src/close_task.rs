@@ -0,0 +1,161 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::CloseTaskArgs,
+    state::{Milestone, Task},
+};
+
+use crate::{
+    escrow::{close_token_account_signed, Escrow, TokenEscrow},
+    withdraw_contribution::settle_contribution,
+};
+
+/// Process CloseTask instruction
+///
+/// Creator closes a completed or cancelled task and reclaims its rent, once no
+/// unclaimed task lists it as a prerequisite. Token tasks
+/// also close the escrow token account, sweeping any balance left in it to the
+/// creator, and every Milestone PDA of the task must be passed so none are left
+/// behind. Contributions that were not withdrawn
+/// are settled on the way: each funder gets its refund share and the Contribution
+/// PDA's rent back, exactly as with WithdrawContribution.
+///
+/// Any number of open contributions can be passed, so a task with many funders is
+/// settled in batches over several calls. The Task PDA (and its escrow and
+/// milestones) is only closed by the call that settles the last one.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Creator wallet (receives rent)
+/// 1. `[writable]` Task PDA
+/// 2. `[]` System program
+///
+/// Token bounties only:
+/// 3. `[]` Mint
+/// 4. `[writable]` Escrow token account
+/// 5. `[]` Token program
+/// 6. `[]` Associated token program
+/// 7. `[writable]` Creator token account (ATA, created if missing)
+/// 8.. `[writable]` Funder token accounts (ATAs, created if missing), one per
+///     contribution settled by this call, in the order below
+///
+/// Then, for milestone tasks:
+/// N.. `[writable]` Milestone PDAs, in index order
+///
+/// Then, for each contribution settled by this call: `[writable]` Contribution PDA
+/// and `[writable]` funder wallet.
+pub fn process_close_task(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [creator_info, task_info, system_program, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let _args = bytemuck::try_from_bytes::<CloseTaskArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    creator_info.is_signer()?;
+
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Only finished tasks can be closed
+    if task.status != STATUS_COMPLETED && task.status != STATUS_CANCELLED {
+        return Err(BountyBoardError::InvalidTaskStatus.into());
+    }
+
+    // Only creator can close (they paid the rent)
+    if task.creator != *creator_info.key {
+        return Err(BountyBoardError::NotCreator.into());
+    }
+
+    // Dependent tasks still need to read this one's status
+    if task.dependents > 0 {
+        return Err(BountyBoardError::HasDependents.into());
+    }
+
+    // Work out how many contributions this call settles: each takes a
+    // Contribution PDA and a funder wallet, plus a funder token account for
+    // token tasks
+    let milestone_count = task.milestone_count as usize;
+    let (fixed_count, per_contribution) = if task.is_token() { (5, 3) } else { (0, 2) };
+    let batch_accounts = remaining
+        .len()
+        .checked_sub(fixed_count + milestone_count)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if batch_accounts % per_contribution != 0 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let batch = batch_accounts / per_contribution;
+    if batch > task.contributors as usize || (batch == 0 && task.contributors > 0) {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let token_count = if task.is_token() { fixed_count + batch } else { 0 };
+    let (token_accounts, remaining) = remaining.split_at(token_count);
+    let (milestone_infos, contribution_infos) = remaining.split_at(milestone_count);
+
+    // Settle contributions that were never withdrawn
+    let mut escrow = Escrow::load(task_info, task, creator_info, system_program, token_accounts)?;
+    let creator_destination = escrow.recipient(creator_info)?;
+    for pair in contribution_infos.chunks_exact(2) {
+        settle_contribution(&mut escrow, task, &pair[0], &pair[1])?;
+    }
+
+    // Leave the task open until the last batch
+    if task.contributors > 0 {
+        solana_program::msg!(
+            "BountyBoard: Task {} settled {} contributions, {} left",
+            task.id,
+            batch,
+            task.contributors
+        );
+        return Ok(());
+    }
+
+    // Anything still in the token escrow belongs to the creator
+    escrow.sweep(creator_destination)?;
+
+    // Close the escrow token account, returning its rent to the creator
+    if let [mint_info, escrow_info, token_program, associated_token_program, ..] = token_accounts {
+        let token = TokenEscrow::new(
+            task_info,
+            mint_info,
+            escrow_info,
+            token_program,
+            associated_token_program,
+        )?;
+        close_token_account_signed(
+            token.escrow,
+            creator_info,
+            task_info,
+            token.token_program,
+            &[TASK, &task.id.to_le_bytes()],
+        )?;
+    }
+
+    // Close every milestone of the task
+    for (index, milestone_info) in milestone_infos.iter().enumerate() {
+        let (milestone_pda_key, _) = milestone_pda(task.id, index as u8);
+        if *milestone_info.key != milestone_pda_key {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        milestone_info.as_account::<Milestone>(&bountyboard_api::ID)?;
+        milestone_info.close(creator_info)?;
+    }
+
+    // Final archival record before the account is gone
+    solana_program::msg!(
+        "BountyBoard: Task {} closed. Status: {}, Creator: {}, Claimer: {}, Bounty: {}",
+        task.id,
+        task.status,
+        task.creator,
+        task.claimer,
+        task.bounty
+    );
+
+    task_info.close(creator_info)?;
+
+    Ok(())
+}
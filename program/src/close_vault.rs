@@ -0,0 +1,45 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::CloseVaultArgs,
+    state::Vault,
+};
+
+/// Process CloseVault instruction
+///
+/// Owner stops a schedule, recovering the vault's remaining balance and rent.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Owner wallet
+/// 1. `[writable]` Vault PDA
+pub fn process_close_vault(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [owner_info, vault_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<CloseVaultArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    owner_info.is_signer()?;
+
+    if *vault_info.key != vault_pda(args.vault_id()).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let vault = vault_info.as_account::<Vault>(&bountyboard_api::ID)?;
+    if vault.owner != *owner_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    vault_info.close(owner_info)?;
+
+    solana_program::msg!("BountyBoard: Vault {} closed", args.vault_id());
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::CounterStakeArgs,
+    state::{Dispute, Task},
+};
+
+/// Process CounterStake instruction
+///
+/// The creator contests a dispute by matching the claimer's dispute stake within
+/// COUNTER_STAKE_WINDOW of it opening. The counter-stake is held in the Task PDA.
+/// Without it, the claimer wins by default once the window lapses.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Creator wallet
+/// 1. `[writable]` Task PDA
+/// 2. `[writable]` Dispute PDA
+/// 3. `[]` System program
+pub fn process_counter_stake(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [creator_info, task_info, dispute_info, _system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let _args = bytemuck::try_from_bytes::<CounterStakeArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    creator_info.is_signer()?;
+
+    let task = task_info.as_account::<Task>(&bountyboard_api::ID)?;
+
+    // Must be disputed
+    if task.status != STATUS_DISPUTED {
+        return Err(BountyBoardError::TaskNotDisputed.into());
+    }
+
+    // Only the creator can counter-stake
+    if task.creator != *creator_info.key {
+        return Err(BountyBoardError::NotCreator.into());
+    }
+
+    // Verify Dispute PDA
+    if *dispute_info.key != dispute_pda(task.id).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let dispute = dispute_info.as_account_mut::<Dispute>(&bountyboard_api::ID)?;
+
+    if dispute.is_contested() {
+        return Err(BountyBoardError::AlreadyCounterStaked.into());
+    }
+    if Clock::get()?.unix_timestamp > dispute.counter_stake_deadline {
+        return Err(BountyBoardError::CounterStakeWindowClosed.into());
+    }
+
+    // Transfer matching stake from creator to task PDA
+    task_info.collect(dispute.stake, creator_info)?;
+    dispute.creator_staked = 1;
+
+    solana_program::msg!(
+        "BountyBoard: Creator counter-staked {} lamports on task {}",
+        dispute.stake,
+        task.id
+    );
+
+    Ok(())
+}
@@ -2,9 +2,8 @@ use solana_program::{
     account_info::AccountInfo,
     clock::Clock,
     entrypoint::ProgramResult,
-    program::invoke,
     program_error::ProgramError,
-    system_instruction,
+    pubkey::Pubkey,
     sysvar::Sysvar,
 };
 use steel::*;
@@ -15,34 +14,138 @@ use bountyboard_api::{
     state::{Config, Task},
 };
 
+use crate::{
+    dependencies::{hold_prerequisites, split_prerequisites},
+    escrow::{transfer_checked, TokenEscrow},
+    reputation::load_agent,
+    tags::validate_tags,
+};
+
 /// Process CreateTask instruction
 ///
-/// Creator posts a task with bounty. SOL is locked in the Task PDA.
+/// Creator posts a task with bounty. SOL is locked in the Task PDA, or for token
+/// bounties, in the Task PDA's associated token account for the mint.
+/// Plain single-worker tasks can be made private: claimable only by a direct hire
+/// (`allowed_claimer`) and/or wallets in a merkle allowlist (`claimer_root`), and
+/// gated on the claimer's reputation (`min_completions`, `min_approval_bps`).
 ///
 /// Accounts:
 /// 0. `[signer, writable]` Creator wallet
 /// 1. `[writable]` Config PDA
 /// 2. `[writable]` Task PDA (to be created)
 /// 3. `[]` System program
+/// 4. `[writable]` Creator Agent PDA (skipped if unregistered)
+/// 5. `[]` Category PDA of the tags' category (ignored if uncategorized)
+///
+/// Token bounties only:
+/// 6. `[]` Mint
+/// 7. `[writable]` Escrow token account (Task PDA's ATA, to be created)
+/// 8. `[]` Token program (SPL Token or Token-2022)
+/// 9. `[]` Associated token program
+/// 10. `[writable]` Creator token account (source of the bounty)
+///
+/// Then, for dependent tasks: `[writable]` prerequisite Task PDAs, in the order of
+/// `dependencies`. Prerequisites must be the creator's own, uncancelled tasks; each
+/// one cannot be closed until this task is claimed, cancelled or expired.
 pub fn process_create_task(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
-    let [creator_info, config_info, task_info, system_program] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-
     let args = bytemuck::try_from_bytes::<CreateTaskArgs>(data)
         .map_err(|_| ProgramError::InvalidInstructionData)?;
 
+    create_task(accounts, args)
+}
+
+/// Creates and funds a task from `args`; shared by CreateTask and
+/// CreateTaskFromTemplate. `accounts` follow the CreateTask layout.
+pub fn create_task(accounts: &[AccountInfo], args: &CreateTaskArgs) -> ProgramResult {
+    let [
+        creator_info,
+        config_info,
+        task_info,
+        system_program,
+        creator_agent_info,
+        category_info,
+        remaining @ ..,
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
     creator_info.is_signer()?;
 
-    let bounty = args.bounty();
-    if bounty < MIN_BOUNTY {
+    if args.dependency_count as usize > MAX_TASK_DEPENDENCIES {
+        return Err(BountyBoardError::InvalidDependencies.into());
+    }
+    let (token_accounts, dependency_infos) =
+        split_prerequisites(remaining, args.dependency_count as usize)?;
+
+    // Token bounties only need to be non-zero; native bounties have a lamport floor.
+    // For multi-slot tasks the floor applies per slot.
+    let bounty_per_slot = args.bounty();
+    let is_token = !token_accounts.is_empty();
+    if bounty_per_slot == 0 || (!is_token && bounty_per_slot < MIN_BOUNTY) {
         return Err(BountyBoardError::BountyTooSmall.into());
     }
 
+    // A single slot is just a regular task
+    let slots = match args.slots() {
+        0 | 1 => 0,
+        slots => slots,
+    };
+    let bounty = if slots > 0 {
+        bounty_per_slot
+            .checked_mul(slots as u64)
+            .ok_or(BountyBoardError::Overflow)?
+    } else {
+        bounty_per_slot
+    };
+
+    let work_window = args.work_window();
+    if work_window < 0 {
+        return Err(BountyBoardError::InvalidWorkWindow.into());
+    }
+
+    // Reject unknown flags; contests take entries, not applications
+    if args.flags & !TASK_FLAGS_ALL != 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let flags = args.flags;
+    if slots > 0 && flags != 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    // Private, reputation-gated and dependent tasks are claimed directly, so only
+    // plain tasks can be restricted
+    let private = args.allowed_claimer != [0u8; 32] || args.claimer_root != [0u8; 32];
+    let gated = args.min_completions() > 0 || args.min_approval_bps() > 0;
+    let dependent = args.dependency_count > 0;
+    if (private || gated || dependent) && (slots > 0 || flags != 0) {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    if args.min_approval_bps() as u64 > TOTAL_BPS {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    if flags & TASK_FLAG_CONTEST != 0 {
+        if flags & TASK_FLAG_APPLICATIONS != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        // Contests need a submission deadline
+        if args.deadline() <= Clock::get()?.unix_timestamp {
+            return Err(BountyBoardError::DeadlinePassed.into());
+        }
+    }
+
+    // Tags must follow the `Tags` layout with a registered category
+    validate_tags(&args.tags, category_info)?;
+
     // Load config to get next task_id
     let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
     let task_id = config.task_count;
 
+    // Prerequisites must already exist, which also rules out cycles
+    if args.dependencies().any(|id| id >= task_id) {
+        return Err(BountyBoardError::InvalidDependencies.into());
+    }
+    hold_prerequisites(creator_info.key, args.dependencies(), dependency_infos)?;
+
     // Verify task PDA
     let (task_pda_key, _task_bump) = task_pda(task_id);
     if *task_info.key != task_pda_key {
@@ -58,34 +161,103 @@ pub fn process_create_task(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
         &[TASK, &task_id.to_le_bytes()],
     )?;
 
-    // Transfer bounty SOL from creator to task PDA (escrow)
-    invoke(
-        &system_instruction::transfer(creator_info.key, task_info.key, bounty),
-        &[creator_info.clone(), task_info.clone()],
-    )?;
+    let mint = match token_accounts {
+        [] => {
+            // Transfer bounty SOL from creator to task PDA (escrow)
+            task_info.collect(bounty, creator_info)?;
+            Pubkey::default()
+        }
+        [mint_info, escrow_info, token_program, associated_token_program, creator_token_info] => {
+            let token = TokenEscrow::new(
+                task_info,
+                mint_info,
+                escrow_info,
+                token_program,
+                associated_token_program,
+            )?;
+
+            // Create the Task PDA's escrow token account and move the bounty into it
+            token.create_escrow(creator_info, task_info, system_program)?;
+            transfer_checked(
+                creator_info,
+                creator_token_info,
+                mint_info,
+                escrow_info,
+                token_program,
+                bounty,
+                token.decimals,
+            )?;
+            *mint_info.key
+        }
+        _ => return Err(ProgramError::NotEnoughAccountKeys),
+    };
 
-    // Initialize task
-    let clock = Clock::get()?;
     let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+    init_task(task, task_id, *creator_info.key, bounty, mint, slots, args)?;
+
+    // Update config (total_escrowed only tracks native SOL)
+    config.task_count = task_id.checked_add(1).ok_or(BountyBoardError::Overflow)?;
+    if !task.is_token() {
+        config.total_escrowed = config
+            .total_escrowed
+            .checked_add(bounty)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    // Update creator reputation
+    if let Some(creator_agent) = load_agent(creator_agent_info, creator_info.key)? {
+        creator_agent.tasks_posted = creator_agent
+            .tasks_posted
+            .checked_add(1)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    if task.is_token() {
+        solana_program::msg!("BountyBoard: Task {} created with bounty {} of mint {}", task_id, bounty, mint);
+    } else {
+        solana_program::msg!("BountyBoard: Task {} created with bounty {} lamports", task_id, bounty);
+    }
+
+    Ok(())
+}
+
+/// Initializes a freshly created, funded Task PDA from `args`; shared by
+/// CreateTask and SpawnScheduledTask. `bounty` is the total escrowed bounty and
+/// `slots` the normalized slot count.
+pub fn init_task(
+    task: &mut Task,
+    task_id: u64,
+    creator: Pubkey,
+    bounty: u64,
+    mint: Pubkey,
+    slots: u32,
+    args: &CreateTaskArgs,
+) -> ProgramResult {
     task.id = task_id;
-    task.creator = *creator_info.key;
-    task.claimer = solana_program::pubkey::Pubkey::default();
+    task.creator = creator;
+    task.claimer = Pubkey::default();
     task.bounty = bounty;
     task.description_hash = args.description_hash;
     task.proof_hash = [0u8; 32];
     task.status = STATUS_OPEN;
-    task.created_at = clock.unix_timestamp;
+    task.created_at = Clock::get()?.unix_timestamp;
     task.deadline = args.deadline();
     task.tags = args.tags;
-
-    // Update config
-    config.task_count = task_id.checked_add(1).ok_or(BountyBoardError::Overflow)?;
-    config.total_escrowed = config
-        .total_escrowed
-        .checked_add(bounty)
-        .ok_or(BountyBoardError::Overflow)?;
-
-    solana_program::msg!("BountyBoard: Task {} created with bounty {} lamports", task_id, bounty);
-
+    task.mint = mint;
+    task.work_window = args.work_window();
+    task.claim_bond = args.claim_bond();
+    task.flags = args.flags;
+    task.slots = slots;
+    task.bounty_per_slot = if slots > 0 { args.bounty() } else { 0 };
+    task.allowed_claimer = args.allowed_claimer();
+    task.claimer_root = args.claimer_root;
+    task.min_completions = args.min_completions();
+    task.min_approval_bps = args.min_approval_bps();
+    task.dependency_count = 0;
+    for (slot, id) in task.dependencies.iter_mut().zip(args.dependencies()) {
+        *slot = id;
+        task.dependency_count += 1;
+    }
+    task.prerequisites_held = (task.dependency_count > 0) as u8;
     Ok(())
 }
@@ -0,0 +1,85 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::{CreateTaskArgs, CreateTaskFromTemplateArgs},
+    state::Template,
+};
+
+use crate::create_task::create_task;
+
+/// Process CreateTaskFromTemplate instruction
+///
+/// Creator posts a task with a template's bounty, description, tags, deadline
+/// (relative to now), work window, claim bond, flags and reputation gate. Anyone
+/// can use any template; the creator funds the bounty as with CreateTask, in the
+/// template's mint. The caller pins the `updated_at` it read, so an owner update
+/// landing first makes the instruction fail instead of changing the terms.
+///
+/// Accounts:
+/// 0. `[writable]` Template PDA
+///
+/// followed by the CreateTask accounts (creator, config, task, system program,
+/// creator agent, category, and the token accounts for token bounties).
+pub fn process_create_task_from_template(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [template_info, task_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<CreateTaskFromTemplateArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    if *template_info.key != template_pda(args.template_id()).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let template = template_info.as_account_mut::<Template>(&bountyboard_api::ID)?;
+    if template.updated_at != args.expected_updated_at() {
+        return Err(BountyBoardError::TemplateChanged.into());
+    }
+
+    // The bounty is denominated in the template's mint
+    match task_accounts.get(6) {
+        None if template.mint == Pubkey::default() => {}
+        Some(mint_info) if *mint_info.key == template.mint => {}
+        _ => return Err(BountyBoardError::InvalidMint.into()),
+    }
+
+    let deadline = if template.deadline_offset > 0 {
+        Clock::get()?
+            .unix_timestamp
+            .checked_add(template.deadline_offset)
+            .ok_or(BountyBoardError::Overflow)?
+    } else {
+        0
+    };
+    let task_args = CreateTaskArgs::new(
+        template.bounty,
+        template.description_hash,
+        deadline,
+        template.tags,
+    )
+    .with_work_window(template.work_window)
+    .with_claim_bond(template.claim_bond)
+    .with_flags(template.flags)
+    .with_min_reputation(template.min_completions, template.min_approval_bps);
+
+    create_task(task_accounts, &task_args)?;
+
+    template.uses = template
+        .uses
+        .checked_add(1)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    solana_program::msg!("BountyBoard: Task created from template {}", template.id);
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::{CreateTemplateArgs, TemplateArgs},
+    state::{Config, Template},
+};
+
+use crate::tags::validate_tags;
+
+/// Process CreateTemplate instruction
+///
+/// Anyone registers a reusable task preset and becomes its owner. Tasks are
+/// posted from it with CreateTaskFromTemplate.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Owner wallet
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Template PDA (to be created)
+/// 3. `[]` System program
+/// 4. `[]` Category PDA of the tags' category (ignored if uncategorized)
+pub fn process_create_template(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [owner_info, config_info, template_info, system_program, category_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<CreateTemplateArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    owner_info.is_signer()?;
+
+    validate_template(&args.template, category_info)?;
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+    let template_id = config.template_count;
+
+    let (template_pda_key, _bump) = template_pda(template_id);
+    if *template_info.key != template_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    create_program_account::<Template>(
+        template_info,
+        system_program,
+        owner_info,
+        &bountyboard_api::ID,
+        &[TEMPLATE, &template_id.to_le_bytes()],
+    )?;
+
+    let template = template_info.as_account_mut::<Template>(&bountyboard_api::ID)?;
+    template.id = template_id;
+    template.owner = *owner_info.key;
+    template.uses = 0;
+    write_template(template, &args.template)?;
+
+    config.template_count = template_id
+        .checked_add(1)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    solana_program::msg!("BountyBoard: Template {} created", template_id);
+
+    Ok(())
+}
+
+/// Checks template settings up front, with the same rules CreateTask applies,
+/// so a template can't hold a preset that every instantiation would reject.
+pub fn validate_template(args: &TemplateArgs, category_info: &AccountInfo) -> ProgramResult {
+    if args.bounty() == 0 || args.deadline_offset() < 0 {
+        return Err(BountyBoardError::InvalidTemplate.into());
+    }
+    if args.mint() == Pubkey::default() && args.bounty() < MIN_BOUNTY {
+        return Err(BountyBoardError::BountyTooSmall.into());
+    }
+    if args.work_window() < 0 {
+        return Err(BountyBoardError::InvalidWorkWindow.into());
+    }
+
+    if args.flags & !TASK_FLAGS_ALL != 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    if args.flags & TASK_FLAG_CONTEST != 0
+        && (args.flags & TASK_FLAG_APPLICATIONS != 0 || args.deadline_offset() == 0)
+    {
+        return Err(BountyBoardError::InvalidTemplate.into());
+    }
+    // Reputation gates only apply to plain tasks
+    let gated = args.min_completions() > 0 || args.min_approval_bps() > 0;
+    if gated && args.flags != 0 {
+        return Err(BountyBoardError::InvalidTemplate.into());
+    }
+    if args.min_approval_bps() as u64 > TOTAL_BPS {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    validate_tags(&args.tags, category_info)
+}
+
+/// Copies validated settings into `template`.
+pub fn write_template(template: &mut Template, args: &TemplateArgs) -> ProgramResult {
+    template.bounty = args.bounty();
+    template.mint = args.mint();
+    template.deadline_offset = args.deadline_offset();
+    template.work_window = args.work_window();
+    template.claim_bond = args.claim_bond();
+    template.description_hash = args.description_hash;
+    template.tags = args.tags;
+    template.min_completions = args.min_completions();
+    template.min_approval_bps = args.min_approval_bps();
+    template.flags = args.flags;
+    // Strictly increasing, even for two updates within the same second
+    template.updated_at = Clock::get()?
+        .unix_timestamp
+        .max(template.updated_at.saturating_add(1));
+    Ok(())
+}
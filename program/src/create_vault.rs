@@ -0,0 +1,102 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::CreateVaultArgs,
+    state::{Config, Vault},
+};
+
+use crate::create_template::validate_template;
+
+/// Process CreateVault instruction
+///
+/// Owner sets up a recurring task: the settings of each task, how often it is
+/// posted and an initial SOL deposit. SpawnScheduledTask posts the tasks from
+/// the vault's balance with the owner as creator.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Owner wallet
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Vault PDA (to be created)
+/// 3. `[]` System program
+/// 4. `[]` Category PDA of the tags' category (ignored if uncategorized)
+pub fn process_create_vault(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [owner_info, config_info, vault_info, system_program, category_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<CreateVaultArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    owner_info.is_signer()?;
+
+    // Scheduled tasks are native SOL bounties
+    if args.task.mint() != Pubkey::default() {
+        return Err(BountyBoardError::InvalidMint.into());
+    }
+    validate_template(&args.task, category_info)?;
+    if args.interval() < MIN_VAULT_INTERVAL {
+        return Err(BountyBoardError::InvalidSchedule.into());
+    }
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+    let vault_id = config.vault_count;
+
+    let (vault_pda_key, _bump) = vault_pda(vault_id);
+    if *vault_info.key != vault_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    create_program_account::<Vault>(
+        vault_info,
+        system_program,
+        owner_info,
+        &bountyboard_api::ID,
+        &[VAULT, &vault_id.to_le_bytes()],
+    )?;
+
+    let deposit = args.deposit();
+    if deposit > 0 {
+        vault_info.collect(deposit, owner_info)?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let task = &args.task;
+    let vault = vault_info.as_account_mut::<Vault>(&bountyboard_api::ID)?;
+    vault.id = vault_id;
+    vault.owner = *owner_info.key;
+    vault.balance = deposit;
+    vault.interval = args.interval();
+    vault.next_spawn_at = args.start_at().max(now);
+    vault.bounty = task.bounty();
+    vault.deadline_offset = task.deadline_offset();
+    vault.work_window = task.work_window();
+    vault.claim_bond = task.claim_bond();
+    vault.description_hash = task.description_hash;
+    vault.tags = task.tags;
+    vault.min_completions = task.min_completions();
+    vault.min_approval_bps = task.min_approval_bps();
+    vault.flags = task.flags;
+    vault.spawned = 0;
+    vault.last_task_id = 0;
+    vault.created_at = now;
+
+    config.vault_count = vault_id.checked_add(1).ok_or(BountyBoardError::Overflow)?;
+
+    solana_program::msg!(
+        "BountyBoard: Vault {} created, posting every {}s with {} lamports deposited",
+        vault_id,
+        vault.interval,
+        deposit
+    );
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    state::Task,
+};
+
+/// Where a dependent task's prerequisites stand, see `release_prerequisites`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Prerequisites {
+    /// Every prerequisite is COMPLETED
+    Completed,
+    /// Some prerequisite is still in progress
+    Pending,
+    /// Some prerequisite was cancelled, so the task can never be claimed
+    Cancelled,
+}
+
+/// Splits the trailing prerequisite Task PDAs off `accounts`: the last `count`
+/// accounts, after any token accounts.
+pub fn split_prerequisites<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    count: usize,
+) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>]), ProgramError> {
+    let at = accounts
+        .len()
+        .checked_sub(count)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    Ok(accounts.split_at(at))
+}
+
+/// Registers a new task as a dependent of each of `dependencies` (passed as
+/// `dependency_infos`, in order). Prerequisites must be `creator`'s own tasks
+/// and not cancelled; their `dependents` count keeps them from being closed.
+pub fn hold_prerequisites(
+    creator: &Pubkey,
+    dependencies: impl Iterator<Item = u64>,
+    dependency_infos: &[AccountInfo],
+) -> ProgramResult {
+    for (id, dependency_info) in dependencies.zip(dependency_infos) {
+        if *dependency_info.key != task_pda(id).0 {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        let dependency = dependency_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+        if dependency.creator != *creator || dependency.status == STATUS_CANCELLED {
+            return Err(BountyBoardError::InvalidDependencies.into());
+        }
+        dependency.dependents = dependency
+            .dependents
+            .checked_add(1)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    Ok(())
+}
+
+/// Releases `task`'s hold on its prerequisites (passed as `dependency_infos`, in
+/// the order of `Task.dependencies`) once it is claimed, cancelled or expired, and
+/// reports their status. A task that no longer holds them was claimed before, so
+/// its prerequisites were already COMPLETED.
+pub fn release_prerequisites(
+    task: &mut Task,
+    dependency_infos: &[AccountInfo],
+) -> Result<Prerequisites, ProgramError> {
+    let dependencies = task.held_prerequisites();
+    if dependency_infos.len() != dependencies.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let mut prerequisites = Prerequisites::Completed;
+    for (id, dependency_info) in dependencies.iter().zip(dependency_infos) {
+        if *dependency_info.key != task_pda(*id).0 {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        let dependency = dependency_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+        dependency.dependents = dependency
+            .dependents
+            .checked_sub(1)
+            .ok_or(BountyBoardError::Overflow)?;
+        match dependency.status {
+            STATUS_COMPLETED => {}
+            STATUS_CANCELLED => prerequisites = Prerequisites::Cancelled,
+            _ if prerequisites == Prerequisites::Completed => prerequisites = Prerequisites::Pending,
+            _ => {}
+        }
+    }
+
+    task.prerequisites_held = 0;
+    Ok(prerequisites)
+}
@@ -0,0 +1,54 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::DepositVaultArgs,
+    state::Vault,
+};
+
+/// Process DepositVault instruction
+///
+/// Owner tops up a vault's balance for future scheduled tasks.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Owner wallet
+/// 1. `[writable]` Vault PDA
+/// 2. `[]` System program
+pub fn process_deposit_vault(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [owner_info, vault_info, _system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<DepositVaultArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let amount = args.amount();
+
+    owner_info.is_signer()?;
+
+    if *vault_info.key != vault_pda(args.vault_id()).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let vault = vault_info.as_account_mut::<Vault>(&bountyboard_api::ID)?;
+    if vault.owner != *owner_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+    if amount == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    vault.balance = vault
+        .balance
+        .checked_add(amount)
+        .ok_or(BountyBoardError::Overflow)?;
+    vault_info.collect(amount, owner_info)?;
+
+    solana_program::msg!("BountyBoard: Vault {} topped up by {} lamports", args.vault_id(), amount);
+
+    Ok(())
+}
@@ -1,30 +1,37 @@
 use solana_program::{
     account_info::AccountInfo,
+    clock::Clock,
     entrypoint::ProgramResult,
-    program::invoke,
     program_error::ProgramError,
-    system_instruction,
+    sysvar::Sysvar,
 };
 use steel::*;
 use bountyboard_api::{
     consts::*,
     error::BountyBoardError,
     instruction::DisputeArgs,
-    state::{Config, Task},
+    state::{Arbiter, Config, Dispute, Task},
 };
 
 /// Process Dispute instruction
 ///
-/// Claimer disputes a rejection by staking dispute_stake SOL into the Task PDA.
-/// Task moves to DISPUTED status. Admin resolves later.
+/// The rejected claimer disputes a rejection within DISPUTE_WINDOW by staking
+/// dispute_stake SOL into the Task PDA. Task moves to DISPUTED status and a
+/// Dispute PDA records the arbiter panel, assigned round-robin from the registry.
+/// With no arbiters registered (or arbiters_per_dispute = 0) the admin resolves.
+/// The creator then has COUNTER_STAKE_WINDOW to match the stake with CounterStake,
+/// or the claimer wins by default.
 ///
 /// Accounts:
 /// 0. `[signer, writable]` Claimer wallet
-/// 1. `[]` Config PDA (read dispute_stake)
+/// 1. `[writable]` Config PDA (read dispute_stake, advance arbiter rotation)
 /// 2. `[writable]` Task PDA
 /// 3. `[]` System program
+/// 4. `[writable]` Dispute PDA (to be created)
+///
+/// Then one `[]` Arbiter PDA per panel seat (see `Config::next_panel`).
 pub fn process_dispute(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
-    let [claimer_info, config_info, task_info, _system_program] = accounts else {
+    let [claimer_info, config_info, task_info, system_program, dispute_info, arbiter_infos @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -33,45 +40,90 @@ pub fn process_dispute(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
 
     claimer_info.is_signer()?;
 
-    let config = config_info.as_account::<Config>(&bountyboard_api::ID)?;
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
     let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
 
-    // Task must be OPEN (just got rejected, claimer was cleared)
-    // Actually after rejection the claimer is cleared. We need the claimer
-    // to still be tracked. Let's check: after reject, task goes to OPEN and claimer is zeroed.
-    // The claimer needs to dispute before someone else claims.
-    // For dispute flow: reject sets status=OPEN, claimer=default.
-    // But we need to know WHO is disputing. The signer is the disputer.
-    // We allow anyone to dispute an OPEN task if they stake — but that doesn't make sense.
-    // 
-    // Better: after reject, keep status as a new "REJECTED" intermediate? No — spec says
-    // reject returns to OPEN. Let's instead: the claimer can only dispute within the same
-    // transaction or we track the previous claimer. 
-    //
-    // Simplification: allow status OPEN (post-rejection) to be disputed by anyone who stakes.
-    // The disputer becomes the new claimer. This is simpler and still secure.
-    
-    if task.status != STATUS_OPEN {
+    // Must be rejected
+    if task.status != STATUS_REJECTED {
         return Err(BountyBoardError::InvalidTaskStatus.into());
     }
 
+    // Only the rejected claimer can dispute
+    if task.claimer != *claimer_info.key {
+        return Err(BountyBoardError::OnlyClaimerCanDispute.into());
+    }
+
+    // Must dispute within the window
+    let now = Clock::get()?.unix_timestamp;
+    let elapsed = now
+        .checked_sub(task.rejected_at)
+        .ok_or(BountyBoardError::Overflow)?;
+    if elapsed > DISPUTE_WINDOW {
+        return Err(BountyBoardError::DisputeWindowClosed.into());
+    }
+
+    // Resolve the arbiter panel
+    let panel = config.next_panel();
+    if arbiter_infos.len() != panel.len() {
+        return Err(BountyBoardError::InvalidArbiterCount.into());
+    }
+
+    // Create Dispute PDA
+    let (dispute_pda_key, _bump) = dispute_pda(task.id);
+    if *dispute_info.key != dispute_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    create_program_account::<Dispute>(
+        dispute_info,
+        system_program,
+        claimer_info,
+        &bountyboard_api::ID,
+        &[DISPUTE, &task.id.to_le_bytes()],
+    )?;
+
     let dispute_stake = config.dispute_stake;
+    let dispute = dispute_info.as_account_mut::<Dispute>(&bountyboard_api::ID)?;
+    dispute.task_id = task.id;
+    dispute.creator = task.creator;
+    dispute.claimer = task.claimer;
+    dispute.stake = dispute_stake;
+    dispute.opened_at = now;
+    dispute.voting_deadline = now
+        .checked_add(DISPUTE_VOTING_PERIOD)
+        .ok_or(BountyBoardError::Overflow)?;
+    dispute.counter_stake_deadline = now
+        .checked_add(COUNTER_STAKE_WINDOW)
+        .ok_or(BountyBoardError::Overflow)?;
+    dispute.expires_at = now
+        .checked_add(config.dispute_resolution_timeout)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    for (slot, (index, arbiter_info)) in panel.iter().zip(arbiter_infos).enumerate() {
+        if *arbiter_info.key != arbiter_pda(*index).0 {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        let arbiter = arbiter_info.as_account::<Arbiter>(&bountyboard_api::ID)?;
+        dispute.arbiters[slot] = arbiter.wallet;
+    }
+    dispute.arbiter_count = panel.len() as u8;
+
+    config.arbiter_cursor = config
+        .arbiter_cursor
+        .checked_add(panel.len() as u64)
+        .ok_or(BountyBoardError::Overflow)?;
 
     // Transfer dispute stake from claimer to task PDA
-    invoke(
-        &system_instruction::transfer(claimer_info.key, task_info.key, dispute_stake),
-        &[claimer_info.clone(), task_info.clone()],
-    )?;
+    task_info.collect(dispute_stake, claimer_info)?;
 
-    // Set task to disputed, record disputer as claimer
-    task.claimer = *claimer_info.key;
+    // Set task to disputed
     task.status = STATUS_DISPUTED;
 
     solana_program::msg!(
-        "BountyBoard: Task {} disputed by {}, stake: {} lamports",
+        "BountyBoard: Task {} disputed by {}, stake: {} lamports, arbiters: {}",
         task.id,
         claimer_info.key,
-        dispute_stake
+        dispute_stake,
+        panel.len()
     );
 
     Ok(())
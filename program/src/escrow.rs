@@ -0,0 +1,381 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program::invoke,
+    program_error::ProgramError,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+use spl_token_2022::{
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    state::{Account as TokenAccount, Mint},
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    state::Task,
+};
+
+/// Token accounts backing an SPL Token / Token-2022 bounty.
+pub struct TokenEscrow<'a, 'info> {
+    pub mint: &'a AccountInfo<'info>,
+    pub escrow: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub decimals: u8,
+}
+
+impl<'a, 'info> TokenEscrow<'a, 'info> {
+    /// Validates the token program, the mint and the Task PDA's escrow token account.
+    pub fn new(
+        task_info: &'a AccountInfo<'info>,
+        mint: &'a AccountInfo<'info>,
+        escrow: &'a AccountInfo<'info>,
+        token_program: &'a AccountInfo<'info>,
+        associated_token_program: &'a AccountInfo<'info>,
+    ) -> Result<Self, ProgramError> {
+        if *token_program.key != spl_token::ID && *token_program.key != spl_token_2022::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if *associated_token_program.key != spl_associated_token_account::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if mint.owner != token_program.key {
+            return Err(BountyBoardError::InvalidMint.into());
+        }
+
+        let escrow_address =
+            get_associated_token_address_with_program_id(task_info.key, mint.key, token_program.key);
+        if *escrow.key != escrow_address {
+            return Err(BountyBoardError::InvalidMint.into());
+        }
+
+        // Escrow accounting assumes a transfer moves exactly the amount sent and
+        // that only the Task PDA can move escrowed tokens, so Token-2022 mints
+        // with transfer fees, transfer hooks or a permanent delegate are refused
+        let decimals = {
+            let data = mint.try_borrow_data()?;
+            let mint_state = StateWithExtensions::<Mint>::unpack(&data)?;
+            let unsupported = mint_state.get_extension_types()?.into_iter().any(|extension| {
+                matches!(
+                    extension,
+                    ExtensionType::TransferFeeConfig
+                        | ExtensionType::TransferHook
+                        | ExtensionType::PermanentDelegate
+                )
+            });
+            if unsupported {
+                return Err(BountyBoardError::UnsupportedMint.into());
+            }
+            mint_state.base.decimals
+        };
+
+        Ok(Self {
+            mint,
+            escrow,
+            token_program,
+            associated_token_program,
+            decimals,
+        })
+    }
+
+    /// Creates the escrow token account, accepting one that already exists. Its
+    /// address is predictable, so anyone could create it ahead of the task; the
+    /// idempotent create still checks the existing account's owner and mint.
+    pub fn create_escrow(
+        &self,
+        payer: &AccountInfo<'info>,
+        task_info: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> ProgramResult {
+        invoke(
+            &create_associated_token_account_idempotent(
+                payer.key,
+                task_info.key,
+                self.mint.key,
+                self.token_program.key,
+            ),
+            &[
+                payer.clone(),
+                self.escrow.clone(),
+                task_info.clone(),
+                self.mint.clone(),
+                system_program.clone(),
+                self.token_program.clone(),
+                self.associated_token_program.clone(),
+            ],
+        )
+    }
+}
+
+/// Moves bounty funds out of a task's escrow.
+///
+/// Native tasks pay lamports directly from the Task PDA. Token tasks pay from the
+/// Task PDA's escrow token account into each recipient's associated token account,
+/// which `payer` creates on demand.
+pub struct Escrow<'a, 'info> {
+    task_info: &'a AccountInfo<'info>,
+    task_id: u64,
+    payer: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    token: Option<TokenEscrow<'a, 'info>>,
+    recipients: std::slice::Iter<'a, AccountInfo<'info>>,
+}
+
+impl<'a, 'info> Escrow<'a, 'info> {
+    /// Loads the escrow for `task`.
+    ///
+    /// For token tasks, `token_accounts` must hold, in order:
+    /// `[mint, escrow token account, token program, associated token program,
+    /// recipient token accounts...]`. Native tasks take no extra accounts.
+    pub fn load(
+        task_info: &'a AccountInfo<'info>,
+        task: &Task,
+        payer: &'a AccountInfo<'info>,
+        system_program: &'a AccountInfo<'info>,
+        token_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let (token, recipients) = if task.is_token() {
+            let [mint_info, escrow_info, token_program, associated_token_program, recipients @ ..] =
+                token_accounts
+            else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            if *mint_info.key != task.mint {
+                return Err(BountyBoardError::InvalidMint.into());
+            }
+            let token = TokenEscrow::new(
+                task_info,
+                mint_info,
+                escrow_info,
+                token_program,
+                associated_token_program,
+            )?;
+            (Some(token), recipients)
+        } else {
+            (None, token_accounts)
+        };
+
+        Ok(Self {
+            task_info,
+            task_id: task.id,
+            payer,
+            system_program,
+            token,
+            recipients: recipients.iter(),
+        })
+    }
+
+    /// Resolves the account that receives funds on behalf of `owner`.
+    ///
+    /// Native tasks pay the wallet itself. Token tasks take the next recipient token
+    /// account, check it is `owner`'s associated token account and create it if needed.
+    pub fn recipient(
+        &mut self,
+        owner: &'a AccountInfo<'info>,
+    ) -> Result<&'a AccountInfo<'info>, ProgramError> {
+        let Some(token) = &self.token else {
+            return Ok(owner);
+        };
+
+        let token_account = self
+            .recipients
+            .next()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let expected = get_associated_token_address_with_program_id(
+            owner.key,
+            token.mint.key,
+            token.token_program.key,
+        );
+        if *token_account.key != expected {
+            return Err(BountyBoardError::InvalidMint.into());
+        }
+
+        invoke(
+            &create_associated_token_account_idempotent(
+                self.payer.key,
+                owner.key,
+                token.mint.key,
+                token.token_program.key,
+            ),
+            &[
+                self.payer.clone(),
+                token_account.clone(),
+                owner.clone(),
+                token.mint.clone(),
+                self.system_program.clone(),
+                token.token_program.clone(),
+                token.associated_token_program.clone(),
+            ],
+        )?;
+
+        Ok(token_account)
+    }
+
+    /// Transfers `amount` of the bounty from escrow to `destination`
+    /// (a wallet for native tasks, a token account from `recipient` otherwise).
+    pub fn release(&self, destination: &AccountInfo<'info>, amount: u64) -> ProgramResult {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        match &self.token {
+            None => {
+                **self.task_info.try_borrow_mut_lamports()? -= amount;
+                **destination.try_borrow_mut_lamports()? += amount;
+            }
+            Some(token) => {
+                transfer_checked_signed(
+                    self.task_info,
+                    token.escrow,
+                    token.mint,
+                    destination,
+                    token.token_program,
+                    amount,
+                    token.decimals,
+                    &[TASK, &self.task_id.to_le_bytes()],
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Transfers whatever is left in the escrow token account (rounding dust, or
+    /// tokens sent to it directly) to `destination` so it can be closed. Native
+    /// tasks have nothing to sweep: their lamports go with the Task PDA.
+    pub fn sweep(&self, destination: &AccountInfo<'info>) -> ProgramResult {
+        let Some(token) = &self.token else {
+            return Ok(());
+        };
+
+        let balance = {
+            let data = token.escrow.try_borrow_data()?;
+            StateWithExtensions::<TokenAccount>::unpack(&data)?.base.amount
+        };
+        self.release(destination, balance)
+    }
+}
+
+/// Refunds `amount` of the bounty to the creator, keeping contributors' pro-rata
+/// share in escrow for WithdrawContribution. Takes the creator's token account
+/// from `escrow` even if the creator's part is zero.
+pub fn refund_creator<'a, 'info>(
+    escrow: &mut Escrow<'a, 'info>,
+    task: &mut Task,
+    creator: &'a AccountInfo<'info>,
+    amount: u64,
+) -> ProgramResult {
+    let pooled = task
+        .contributors_share(amount)
+        .ok_or(BountyBoardError::Overflow)?;
+    let creator_part = amount.checked_sub(pooled).ok_or(BountyBoardError::Overflow)?;
+    let creator_destination = escrow.recipient(creator)?;
+    escrow.release(creator_destination, creator_part)?;
+    task.refund_pool = task
+        .refund_pool
+        .checked_add(pooled)
+        .ok_or(BountyBoardError::Overflow)?;
+    Ok(())
+}
+
+// Token CPIs. Steel's `spl` feature would provide these, but it links a second
+// spl-token-2022 release next to the one spl-associated-token-account uses.
+
+/// Transfers `amount` tokens from `from_info`, signed by `authority_info`.
+pub fn transfer_checked<'info>(
+    authority_info: &AccountInfo<'info>,
+    from_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    to_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+    decimals: u8,
+) -> ProgramResult {
+    invoke(
+        &spl_token_2022::instruction::transfer_checked(
+            token_program.key,
+            from_info.key,
+            mint_info.key,
+            to_info.key,
+            authority_info.key,
+            &[],
+            amount,
+            decimals,
+        )?,
+        &[
+            token_program.clone(),
+            from_info.clone(),
+            mint_info.clone(),
+            to_info.clone(),
+            authority_info.clone(),
+        ],
+    )
+}
+
+/// Transfers `amount` tokens from `from_info`, signed by the PDA `authority_info`
+/// derived from `seeds`.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked_signed<'info>(
+    authority_info: &AccountInfo<'info>,
+    from_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    to_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+    decimals: u8,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token_2022::instruction::transfer_checked(
+            token_program.key,
+            from_info.key,
+            mint_info.key,
+            to_info.key,
+            authority_info.key,
+            &[],
+            amount,
+            decimals,
+        )?,
+        &[
+            token_program.clone(),
+            from_info.clone(),
+            mint_info.clone(),
+            to_info.clone(),
+            authority_info.clone(),
+        ],
+        &bountyboard_api::ID,
+        seeds,
+    )
+}
+
+/// Closes the token account `account_info`, signed by the PDA `owner_info`
+/// derived from `seeds`, and sends its rent to `destination_info`.
+pub fn close_token_account_signed<'info>(
+    account_info: &AccountInfo<'info>,
+    destination_info: &AccountInfo<'info>,
+    owner_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token_2022::instruction::close_account(
+            token_program.key,
+            account_info.key,
+            destination_info.key,
+            owner_info.key,
+            &[],
+        )?,
+        &[
+            token_program.clone(),
+            account_info.clone(),
+            destination_info.clone(),
+            owner_info.clone(),
+        ],
+        &bountyboard_api::ID,
+        seeds,
+    )
+}
@@ -0,0 +1,127 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::ExpireDisputeArgs,
+    state::{Config, Dispute, Task},
+};
+
+use crate::escrow::{refund_creator, Escrow};
+
+/// Process ExpireDispute instruction
+///
+/// Permissionless timeout: if a dispute is still unresolved after the resolution
+/// timeout it was opened with, anyone can call this to unwind it. Both sides are
+/// refunded — the unreleased bounty and counter-stake to the creator, the dispute
+/// stake and claim bond to the claimer — with no protocol fee. The task is
+/// cancelled. Disputes whose panel reached a decision must be settled with
+/// FinalizeDispute instead.
+///
+/// Accounts:
+/// 0. `[signer]` Caller (anyone — pays for a missing creator token account)
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Task PDA
+/// 3. `[writable]` Dispute PDA (closed, rent to claimer)
+/// 4. `[writable]` Claimer wallet (receives stake and claim bond)
+/// 5. `[writable]` Creator wallet (receives bounty refund and counter-stake)
+/// 6. `[]` System program
+///
+/// Token bounties only:
+/// 7. `[]` Mint
+/// 8. `[writable]` Escrow token account
+/// 9. `[]` Token program
+/// 10. `[]` Associated token program
+/// 11. `[writable]` Creator token account (ATA, created if missing)
+pub fn process_expire_dispute(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [
+        caller_info,
+        config_info,
+        task_info,
+        dispute_info,
+        claimer_info,
+        creator_info,
+        system_program,
+        token_accounts @ ..,
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let _args = bytemuck::try_from_bytes::<ExpireDisputeArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    caller_info.is_signer()?;
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Must be disputed
+    if task.status != STATUS_DISPUTED {
+        return Err(BountyBoardError::TaskNotDisputed.into());
+    }
+
+    // Verify accounts match task
+    if task.claimer != *claimer_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if task.creator != *creator_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Verify Dispute PDA
+    if *dispute_info.key != dispute_pda(task.id).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let dispute = *dispute_info.as_account::<Dispute>(&bountyboard_api::ID)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if now <= dispute.expires_at {
+        return Err(BountyBoardError::DisputeNotExpired.into());
+    }
+    if dispute.decision(now).is_some() {
+        return Err(BountyBoardError::DisputeDecided.into());
+    }
+
+    // Refund the unreleased bounty to the creator and contributors
+    let bounty = task.remaining();
+    let mut escrow = Escrow::load(task_info, task, caller_info, system_program, token_accounts)?;
+    refund_creator(&mut escrow, task, creator_info, bounty)?;
+
+    // Return the dispute stake and claim bond to the claimer
+    let refund = dispute
+        .stake
+        .checked_add(task.claim_bond)
+        .ok_or(BountyBoardError::Overflow)?;
+    task_info.send(refund, claimer_info);
+
+    // Return the creator's counter-stake
+    task_info.send(dispute.creator_stake(), creator_info);
+
+    task.status = STATUS_CANCELLED;
+
+    // Update config
+    if !task.is_token() {
+        config.total_escrowed = config
+            .total_escrowed
+            .checked_sub(bounty)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    dispute_info.close(claimer_info)?;
+
+    solana_program::msg!(
+        "BountyBoard: Dispute on task {} expired — {} refunded to creator, {} lamports to claimer",
+        task.id,
+        bounty,
+        refund
+    );
+
+    Ok(())
+}
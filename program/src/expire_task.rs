@@ -0,0 +1,125 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::ExpireTaskArgs,
+    state::{Config, Task},
+};
+
+use crate::{
+    cancel_task::refund_open_task,
+    dependencies::{release_prerequisites, split_prerequisites, Prerequisites},
+    escrow::{refund_creator, Escrow},
+};
+
+/// Process ExpireTask instruction
+///
+/// Permissionless: once a task passes its deadline without work being submitted,
+/// anyone can cancel it and refund the unreleased bounty to the creator (and
+/// contributors, through WithdrawContribution).
+/// - OPEN tasks expire as soon as the deadline passes, and multi-slot tasks
+///   refund their free slots as with CancelTask. Contests with entries never
+///   expire: they are awarded, or split among the entrants by ClaimContestShare
+///   once CONTEST_AWARD_WINDOW has passed.
+/// - CLAIMED tasks expire after the deadline plus DEADLINE_GRACE_PERIOD; the late
+///   claimer's claim bond is forfeited to the creator.
+/// - Unclaimed dependent tasks also expire, deadline or not, as soon as one of
+///   their prerequisites is cancelled, since they can never be claimed.
+///
+/// Accounts:
+/// 0. `[signer]` Caller (anyone — pays for a missing creator token account)
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Task PDA
+/// 3. `[writable]` Creator wallet (receives the refund and any claim bond)
+/// 4. `[]` System program
+///
+/// Token bounties only:
+/// 5. `[]` Mint
+/// 6. `[writable]` Escrow token account
+/// 7. `[]` Token program
+/// 8. `[]` Associated token program
+/// 9. `[writable]` Creator token account (ATA, created if missing)
+///
+/// Then, for unclaimed dependent tasks: `[writable]` prerequisite Task PDAs, in
+/// the order of `Task.dependencies`.
+pub fn process_expire_task(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [caller_info, config_info, task_info, creator_info, system_program, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let _args = bytemuck::try_from_bytes::<ExpireTaskArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    caller_info.is_signer()?;
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    if task.creator != *creator_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Let go of the prerequisites so they can be closed
+    let (token_accounts, dependency_infos) =
+        split_prerequisites(remaining, task.held_prerequisites().len())?;
+    let orphaned = release_prerequisites(task, dependency_infos)? == Prerequisites::Cancelled;
+
+    // Tasks without a deadline never expire
+    if task.deadline == 0 && !orphaned {
+        return Err(BountyBoardError::TaskNotExpired.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    match task.status {
+        STATUS_OPEN => {
+            if now <= task.deadline && !orphaned {
+                return Err(BountyBoardError::TaskNotExpired.into());
+            }
+
+            // Entrants have already done the work, so the bounty goes to them
+            if task.is_contest() && task.entries > 0 {
+                return Err(BountyBoardError::ContestHasEntries.into());
+            }
+        }
+        STATUS_CLAIMED => {
+            // The claimer keeps the grace period to submit
+            if !task.submission_closed(now) {
+                return Err(BountyBoardError::TaskNotExpired.into());
+            }
+
+            // Claim bond forfeited to the creator
+            task_info.send(task.claim_bond, creator_info);
+        }
+        _ => return Err(BountyBoardError::InvalidTaskStatus.into()),
+    }
+
+    // Refund bounty from escrow to creator and contributors
+    let mut escrow = Escrow::load(task_info, task, caller_info, system_program, token_accounts)?;
+    let bounty = if task.status == STATUS_OPEN {
+        refund_open_task(&mut escrow, task, creator_info)?
+    } else {
+        let bounty = task.remaining();
+        refund_creator(&mut escrow, task, creator_info, bounty)?;
+        task.status = STATUS_CANCELLED;
+        bounty
+    };
+
+    // Update config
+    if !task.is_token() {
+        config.total_escrowed = config
+            .total_escrowed
+            .checked_sub(bounty)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    solana_program::msg!("BountyBoard: Task {} expired, refunded {}", task.id, bounty);
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::FinalizeDisputeArgs,
+    state::{Config, Dispute, Task},
+};
+
+use crate::settlement::{settle_dispute, DisputeAccounts};
+
+/// Process FinalizeDispute instruction
+///
+/// Permissionless: settles a dispute once it has a decision — the claimer by
+/// default if the creator never counter-staked, otherwise the arbiter panel's
+/// strict majority at any time, or its plurality after the voting deadline.
+/// Arbiters who voted for the outcome share ARBITER_STAKE_SHARE_BPS of the
+/// forfeited stake. Payouts match ResolveDispute with a claimer share of
+/// 10000 or 0 bps.
+///
+/// Accounts:
+/// 0. `[signer]` Caller (anyone — pays for missing token accounts)
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Task PDA
+/// 3. `[writable]` Dispute PDA (closed, rent to claimer)
+/// 4. `[writable]` Claimer wallet
+/// 5. `[writable]` Creator wallet
+/// 6. `[writable]` Treasury PDA
+/// 7. `[]` System program
+/// 8. `[writable]` Creator Agent PDA (skipped if unregistered)
+/// 9. `[writable]` Claimer Agent PDA (skipped if unregistered)
+///
+/// Then one `[writable]` wallet per panel arbiter, in panel order.
+///
+/// Token bounties only, after the arbiters: same trailing layout as ResolveDispute.
+pub fn process_finalize_dispute(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [
+        caller_info,
+        config_info,
+        task_info,
+        dispute_info,
+        claimer_info,
+        creator_info,
+        treasury_info,
+        system_program,
+        creator_agent_info,
+        claimer_agent_info,
+        remaining @ ..,
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let _args = bytemuck::try_from_bytes::<FinalizeDisputeArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    caller_info.is_signer()?;
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Must be disputed
+    if task.status != STATUS_DISPUTED {
+        return Err(BountyBoardError::TaskNotDisputed.into());
+    }
+
+    // Verify Dispute PDA
+    if *dispute_info.key != dispute_pda(task.id).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let dispute = *dispute_info.as_account::<Dispute>(&bountyboard_api::ID)?;
+
+    let decision = dispute
+        .decision(Clock::get()?.unix_timestamp)
+        .ok_or(BountyBoardError::NoDisputeDecision)?;
+
+    // Verify panel wallets
+    let panel = dispute.arbiter_count as usize;
+    if remaining.len() < panel {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (arbiter_infos, token_accounts) = remaining.split_at(panel);
+    for (arbiter_info, wallet) in arbiter_infos.iter().zip(&dispute.arbiters) {
+        if arbiter_info.key != wallet {
+            return Err(BountyBoardError::NotArbiter.into());
+        }
+    }
+
+    // Arbiters who voted for the outcome share in a forfeited stake
+    let majority: Vec<&AccountInfo> = arbiter_infos
+        .iter()
+        .zip(&dispute.votes)
+        .filter(|(_, vote)| **vote == decision)
+        .map(|(arbiter_info, _)| arbiter_info)
+        .collect();
+
+    settle_dispute(
+        &DisputeAccounts {
+            payer: caller_info,
+            task: task_info,
+            dispute: dispute_info,
+            claimer: claimer_info,
+            creator: creator_info,
+            treasury: treasury_info,
+            system_program,
+            creator_agent: creator_agent_info,
+            claimer_agent: claimer_agent_info,
+            token_accounts,
+        },
+        config,
+        task,
+        if decision == VOTE_CLAIMER { TOTAL_BPS as u16 } else { 0 },
+        &majority,
+    )
+}
@@ -0,0 +1,151 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::IncreaseBountyArgs,
+    state::{Config, Contribution, Task},
+};
+
+use crate::escrow::{transfer_checked, TokenEscrow};
+
+/// Process IncreaseBounty instruction
+///
+/// Anyone tops up the bounty of an OPEN or CLAIMED task. Funds are added to the
+/// task's escrow in its own currency and recorded on the funder's Contribution PDA.
+/// If the task is later refunded (cancelled, expired or lost in a dispute), each
+/// funder withdraws a pro-rata share with WithdrawContribution; approval stays
+/// with the creator. Milestone and multi-slot tasks cannot be topped up, since
+/// their bounty is fixed by the milestone split or per slot.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Funder wallet
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Task PDA
+/// 3. `[]` System program
+/// 4. `[writable]` Contribution PDA (created on the funder's first top-up)
+///
+/// Token bounties only:
+/// 5. `[]` Mint
+/// 6. `[writable]` Escrow token account
+/// 7. `[]` Token program
+/// 8. `[]` Associated token program
+/// 9. `[writable]` Funder token account (source of the top-up)
+pub fn process_increase_bounty(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [funder_info, config_info, task_info, system_program, contribution_info, token_accounts @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<IncreaseBountyArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let amount = args.amount();
+
+    funder_info.is_signer()?;
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    if amount == 0 || (!task.is_token() && amount < MIN_CONTRIBUTION) {
+        return Err(BountyBoardError::BountyTooSmall.into());
+    }
+
+    if task.status != STATUS_OPEN && task.status != STATUS_CLAIMED {
+        return Err(BountyBoardError::InvalidTaskStatus.into());
+    }
+    if task.has_milestones() {
+        return Err(BountyBoardError::HasMilestones.into());
+    }
+    if task.has_slots() {
+        return Err(BountyBoardError::HasSlots.into());
+    }
+
+    if task.is_token() {
+        let [mint_info, escrow_info, token_program, associated_token_program, funder_token_info] = token_accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        if *mint_info.key != task.mint {
+            return Err(BountyBoardError::InvalidMint.into());
+        }
+        let token = TokenEscrow::new(
+            task_info,
+            mint_info,
+            escrow_info,
+            token_program,
+            associated_token_program,
+        )?;
+        transfer_checked(
+            funder_info,
+            funder_token_info,
+            mint_info,
+            escrow_info,
+            token_program,
+            amount,
+            token.decimals,
+        )?;
+    } else {
+        task_info.collect(amount, funder_info)?;
+        config.total_escrowed = config
+            .total_escrowed
+            .checked_add(amount)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    // Record the contribution
+    let (contribution_pda_key, _bump) = contribution_pda(task.id, funder_info.key);
+    if *contribution_info.key != contribution_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if contribution_info.data_is_empty() {
+        create_program_account::<Contribution>(
+            contribution_info,
+            system_program,
+            funder_info,
+            &bountyboard_api::ID,
+            &[CONTRIBUTION, &task.id.to_le_bytes(), funder_info.key.as_ref()],
+        )?;
+        let contribution = contribution_info.as_account_mut::<Contribution>(&bountyboard_api::ID)?;
+        contribution.task_id = task.id;
+        contribution.funder = *funder_info.key;
+        contribution.created_at = Clock::get()?.unix_timestamp;
+        task.contributors = task
+            .contributors
+            .checked_add(1)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+    let contribution = contribution_info.as_account_mut::<Contribution>(&bountyboard_api::ID)?;
+    contribution.amount = contribution
+        .amount
+        .checked_add(amount)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    task.bounty = task
+        .bounty
+        .checked_add(amount)
+        .ok_or(BountyBoardError::Overflow)?;
+    task.contributed = task
+        .contributed
+        .checked_add(amount)
+        .ok_or(BountyBoardError::Overflow)?;
+    task.contributed_open = task
+        .contributed_open
+        .checked_add(amount)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    solana_program::msg!(
+        "BountyBoard: Task {} bounty increased by {} to {} (from {})",
+        task.id,
+        amount,
+        task.bounty,
+        funder_info.key
+    );
+
+    Ok(())
+}
@@ -6,8 +6,9 @@ use solana_program::{
 use steel::*;
 use bountyboard_api::{
     consts::*,
+    error::BountyBoardError,
     instruction::InitializeArgs,
-    state::Config,
+    state::{Config, Treasury},
 };
 
 /// Process Initialize instruction
@@ -31,6 +32,11 @@ pub fn process_initialize(accounts: &[AccountInfo], data: &[u8]) -> ProgramResul
     // Validate signer
     admin_info.is_signer()?;
 
+    // Fee cannot exceed 100%
+    if args.protocol_fee_bps() as u64 > TOTAL_BPS {
+        return Err(BountyBoardError::InvalidFee.into());
+    }
+
     // Verify PDAs
     let (config_pda_key, _config_bump) = config_pda();
     let (treasury_pda_key, _treasury_bump) = treasury_pda();
@@ -59,12 +65,27 @@ pub fn process_initialize(accounts: &[AccountInfo], data: &[u8]) -> ProgramResul
     config.total_escrowed = 0;
     config.total_completed = 0;
     config.dispute_stake = args.dispute_stake();
+    config.default_work_window = DEFAULT_WORK_WINDOW;
+    config.pending_admin = solana_program::pubkey::Pubkey::default();
+    config.total_fees_collected = 0;
+    config.arbiter_count = 0;
+    config.arbiters_per_dispute = DEFAULT_ARBITERS_PER_DISPUTE;
+    config.arbiter_cursor = 0;
+    config.dispute_resolution_timeout = DEFAULT_DISPUTE_RESOLUTION_TIMEOUT;
+    config.template_count = 0;
+    config.vault_count = 0;
 
-    // Create Treasury PDA (just needs to exist as a SOL holder)
-    // We use create_account directly since Treasury is just a SOL escrow PDA
-    // with no data — the PDA itself holds lamports.
-    // Actually, for simplicity, the treasury is just a PDA address.
-    // No account data needed — it just receives lamports from fee transfers.
+    // Create Treasury PDA. It must be program-owned so WithdrawTreasury can move
+    // lamports out of it.
+    create_program_account::<Treasury>(
+        treasury_info,
+        system_program,
+        admin_info,
+        &bountyboard_api::ID,
+        &[TREASURY],
+    )?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&bountyboard_api::ID)?;
+    treasury.total_withdrawn = 0;
 
     solana_program::msg!("BountyBoard: Protocol initialized");
     solana_program::msg!("  Admin: {}", config.admin);
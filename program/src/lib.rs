@@ -8,6 +8,59 @@ mod dispute;
 mod resolve_dispute;
 mod cancel_task;
 mod claim_expired;
+mod register_agent;
+mod reopen_task;
+mod add_milestone;
+mod submit_milestone;
+mod approve_milestone;
+mod reject_milestone;
+mod release_stale_claim;
+mod update_config;
+mod propose_admin;
+mod accept_admin;
+mod withdraw_treasury;
+mod close_task;
+mod submit_application;
+mod accept_application;
+mod close_application;
+mod submit_entry;
+mod award_contest;
+mod close_submission;
+mod claim_slot;
+mod submit_slot;
+mod approve_slot;
+mod reject_slot;
+mod add_arbiter;
+mod remove_arbiter;
+mod cast_vote;
+mod finalize_dispute;
+mod expire_dispute;
+mod counter_stake;
+mod submit_evidence;
+mod expire_task;
+mod increase_bounty;
+mod update_task;
+mod withdraw_contribution;
+mod add_category;
+mod update_category;
+mod create_template;
+mod update_template;
+mod create_task_from_template;
+mod create_vault;
+mod deposit_vault;
+mod withdraw_vault;
+mod close_vault;
+mod spawn_scheduled_task;
+mod release_stale_slot;
+mod claim_expired_slot;
+mod claim_expired_milestone;
+mod claim_contest_share;
+
+mod dependencies;
+mod escrow;
+mod reputation;
+mod settlement;
+mod tags;
 
 use initialize::*;
 use create_task::*;
@@ -19,6 +72,53 @@ use dispute::*;
 use resolve_dispute::*;
 use cancel_task::*;
 use claim_expired::*;
+use register_agent::*;
+use reopen_task::*;
+use add_milestone::*;
+use submit_milestone::*;
+use approve_milestone::*;
+use reject_milestone::*;
+use release_stale_claim::*;
+use update_config::*;
+use propose_admin::*;
+use accept_admin::*;
+use withdraw_treasury::*;
+use close_task::*;
+use submit_application::*;
+use accept_application::*;
+use close_application::*;
+use submit_entry::*;
+use award_contest::*;
+use close_submission::*;
+use claim_slot::*;
+use submit_slot::*;
+use approve_slot::*;
+use reject_slot::*;
+use add_arbiter::*;
+use remove_arbiter::*;
+use cast_vote::*;
+use finalize_dispute::*;
+use expire_dispute::*;
+use counter_stake::*;
+use submit_evidence::*;
+use expire_task::*;
+use increase_bounty::*;
+use update_task::*;
+use withdraw_contribution::*;
+use add_category::*;
+use update_category::*;
+use create_template::*;
+use update_template::*;
+use create_task_from_template::*;
+use create_vault::*;
+use deposit_vault::*;
+use withdraw_vault::*;
+use close_vault::*;
+use spawn_scheduled_task::*;
+use release_stale_slot::*;
+use claim_expired_slot::*;
+use claim_expired_milestone::*;
+use claim_contest_share::*;
 
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
@@ -52,6 +152,55 @@ pub fn process_instruction(
         BountyBoardInstruction::ResolveDispute => process_resolve_dispute(accounts, data)?,
         BountyBoardInstruction::CancelTask => process_cancel_task(accounts, data)?,
         BountyBoardInstruction::ClaimExpired => process_claim_expired(accounts, data)?,
+        BountyBoardInstruction::RegisterAgent => process_register_agent(accounts, data)?,
+        BountyBoardInstruction::ReopenTask => process_reopen_task(accounts, data)?,
+        BountyBoardInstruction::AddMilestone => process_add_milestone(accounts, data)?,
+        BountyBoardInstruction::SubmitMilestone => process_submit_milestone(accounts, data)?,
+        BountyBoardInstruction::ApproveMilestone => process_approve_milestone(accounts, data)?,
+        BountyBoardInstruction::RejectMilestone => process_reject_milestone(accounts, data)?,
+        BountyBoardInstruction::ReleaseStaleClaim => process_release_stale_claim(accounts, data)?,
+        BountyBoardInstruction::UpdateConfig => process_update_config(accounts, data)?,
+        BountyBoardInstruction::ProposeAdmin => process_propose_admin(accounts, data)?,
+        BountyBoardInstruction::AcceptAdmin => process_accept_admin(accounts, data)?,
+        BountyBoardInstruction::WithdrawTreasury => process_withdraw_treasury(accounts, data)?,
+        BountyBoardInstruction::CloseTask => process_close_task(accounts, data)?,
+        BountyBoardInstruction::SubmitApplication => process_submit_application(accounts, data)?,
+        BountyBoardInstruction::AcceptApplication => process_accept_application(accounts, data)?,
+        BountyBoardInstruction::CloseApplication => process_close_application(accounts, data)?,
+        BountyBoardInstruction::SubmitEntry => process_submit_entry(accounts, data)?,
+        BountyBoardInstruction::AwardContest => process_award_contest(accounts, data)?,
+        BountyBoardInstruction::CloseSubmission => process_close_submission(accounts, data)?,
+        BountyBoardInstruction::ClaimSlot => process_claim_slot(accounts, data)?,
+        BountyBoardInstruction::SubmitSlot => process_submit_slot(accounts, data)?,
+        BountyBoardInstruction::ApproveSlot => process_approve_slot(accounts, data)?,
+        BountyBoardInstruction::RejectSlot => process_reject_slot(accounts, data)?,
+        BountyBoardInstruction::AddArbiter => process_add_arbiter(accounts, data)?,
+        BountyBoardInstruction::RemoveArbiter => process_remove_arbiter(accounts, data)?,
+        BountyBoardInstruction::CastVote => process_cast_vote(accounts, data)?,
+        BountyBoardInstruction::FinalizeDispute => process_finalize_dispute(accounts, data)?,
+        BountyBoardInstruction::ExpireDispute => process_expire_dispute(accounts, data)?,
+        BountyBoardInstruction::CounterStake => process_counter_stake(accounts, data)?,
+        BountyBoardInstruction::SubmitEvidence => process_submit_evidence(accounts, data)?,
+        BountyBoardInstruction::ExpireTask => process_expire_task(accounts, data)?,
+        BountyBoardInstruction::IncreaseBounty => process_increase_bounty(accounts, data)?,
+        BountyBoardInstruction::UpdateTask => process_update_task(accounts, data)?,
+        BountyBoardInstruction::WithdrawContribution => process_withdraw_contribution(accounts, data)?,
+        BountyBoardInstruction::AddCategory => process_add_category(accounts, data)?,
+        BountyBoardInstruction::UpdateCategory => process_update_category(accounts, data)?,
+        BountyBoardInstruction::CreateTemplate => process_create_template(accounts, data)?,
+        BountyBoardInstruction::UpdateTemplate => process_update_template(accounts, data)?,
+        BountyBoardInstruction::CreateTaskFromTemplate => {
+            process_create_task_from_template(accounts, data)?
+        }
+        BountyBoardInstruction::CreateVault => process_create_vault(accounts, data)?,
+        BountyBoardInstruction::DepositVault => process_deposit_vault(accounts, data)?,
+        BountyBoardInstruction::WithdrawVault => process_withdraw_vault(accounts, data)?,
+        BountyBoardInstruction::CloseVault => process_close_vault(accounts, data)?,
+        BountyBoardInstruction::SpawnScheduledTask => process_spawn_scheduled_task(accounts, data)?,
+        BountyBoardInstruction::ReleaseStaleSlot => process_release_stale_slot(accounts, data)?,
+        BountyBoardInstruction::ClaimExpiredSlot => process_claim_expired_slot(accounts, data)?,
+        BountyBoardInstruction::ClaimExpiredMilestone => process_claim_expired_milestone(accounts, data)?,
+        BountyBoardInstruction::ClaimContestShare => process_claim_contest_share(accounts, data)?,
     }
 
     Ok(())
@@ -0,0 +1,43 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    error::BountyBoardError,
+    instruction::ProposeAdminArgs,
+    state::Config,
+};
+
+/// Process ProposeAdmin instruction
+///
+/// Admin nominates a successor. The role only moves once the successor signs
+/// AcceptAdmin, so a mistyped key cannot lock the protocol.
+///
+/// Accounts:
+/// 0. `[signer]` Admin wallet
+/// 1. `[writable]` Config PDA
+pub fn process_propose_admin(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [admin_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<ProposeAdminArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    admin_info.is_signer()?;
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+
+    // Verify admin
+    if config.admin != *admin_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    config.pending_admin = args.new_admin();
+
+    solana_program::msg!("BountyBoard: Admin {} proposed", config.pending_admin);
+
+    Ok(())
+}
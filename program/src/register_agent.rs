@@ -0,0 +1,52 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    state::Agent,
+};
+
+/// Process RegisterAgent instruction
+///
+/// Creates the signer's Agent reputation PDA. Lifecycle instructions only
+/// update reputation for wallets that have registered.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Agent wallet
+/// 1. `[writable]` Agent PDA (to be created)
+/// 2. `[]` System program
+pub fn process_register_agent(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let [wallet_info, agent_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    wallet_info.is_signer()?;
+
+    // Verify agent PDA
+    let (agent_pda_key, _agent_bump) = agent_pda(wallet_info.key);
+    if *agent_info.key != agent_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Create Agent PDA
+    create_program_account::<Agent>(
+        agent_info,
+        system_program,
+        wallet_info,
+        &bountyboard_api::ID,
+        &[AGENT, wallet_info.key.as_ref()],
+    )?;
+
+    let agent = agent_info.as_account_mut::<Agent>(&bountyboard_api::ID)?;
+    agent.wallet = *wallet_info.key;
+    agent.registered_at = Clock::get()?.unix_timestamp;
+
+    solana_program::msg!("BountyBoard: Agent {} registered", wallet_info.key);
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::RejectMilestoneArgs,
+    state::{Milestone, Task},
+};
+
+use crate::reputation::load_agent;
+
+/// Process RejectMilestone instruction
+///
+/// Creator rejects a submitted milestone. The milestone goes back to PENDING and
+/// the task moves to REJECTED, so the claimer may dispute the unreleased remainder
+/// within DISPUTE_WINDOW. Released milestones are unaffected.
+///
+/// Accounts:
+/// 0. `[signer]` Creator wallet
+/// 1. `[writable]` Task PDA
+/// 2. `[writable]` Milestone PDA
+/// 3. `[writable]` Claimer Agent PDA (skipped if unregistered)
+pub fn process_reject_milestone(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [creator_info, task_info, milestone_info, claimer_agent_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<RejectMilestoneArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    creator_info.is_signer()?;
+
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Must be submitted
+    if task.status != STATUS_SUBMITTED {
+        return Err(BountyBoardError::InvalidTaskStatus.into());
+    }
+
+    // Only creator can reject
+    if task.creator != *creator_info.key {
+        return Err(BountyBoardError::NotCreator.into());
+    }
+
+    // Verify milestone PDA
+    let (milestone_pda_key, _milestone_bump) = milestone_pda(task.id, args.index);
+    if *milestone_info.key != milestone_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let milestone = milestone_info.as_account_mut::<Milestone>(&bountyboard_api::ID)?;
+    if milestone.status != MILESTONE_SUBMITTED {
+        return Err(BountyBoardError::InvalidMilestone.into());
+    }
+
+    // Update claimer reputation
+    if let Some(claimer_agent) = load_agent(claimer_agent_info, &task.claimer)? {
+        claimer_agent.tasks_rejected = claimer_agent
+            .tasks_rejected
+            .checked_add(1)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    // Milestone can be resubmitted by whoever holds the task next
+    milestone.proof_hash = [0u8; 32];
+    milestone.submitted_at = 0;
+    milestone.status = MILESTONE_PENDING;
+
+    // Hold the task for the dispute window
+    task.rejected_at = Clock::get()?.unix_timestamp;
+    task.status = STATUS_REJECTED;
+
+    solana_program::msg!(
+        "BountyBoard: Task {} milestone {} rejected, dispute window open",
+        task.id,
+        args.index
+    );
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::RejectSlotArgs,
+    state::{Slot, Task},
+};
+
+use crate::reputation::load_agent;
+
+/// Process RejectSlot instruction
+///
+/// Creator rejects a slot submission. The Slot PDA is closed (rent and claim bond
+/// back to the claimer) and the slot becomes free for another worker.
+///
+/// Unlike RejectWork, a rejected slot cannot be disputed. To bound how much work
+/// a creator can turn down unpaid, a task rejects at most `slots` submissions in
+/// total; past that, submissions must be approved or are paid out by
+/// ClaimExpiredSlot after the review timeout.
+///
+/// Accounts:
+/// 0. `[signer]` Creator wallet
+/// 1. `[writable]` Task PDA
+/// 2. `[writable]` Slot PDA (closed)
+/// 3. `[writable]` Slot claimer wallet (receives claim bond and slot rent)
+/// 4. `[writable]` Claimer Agent PDA (skipped if unregistered)
+pub fn process_reject_slot(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [creator_info, task_info, slot_info, claimer_info, claimer_agent_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<RejectSlotArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    creator_info.is_signer()?;
+
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Only creator can reject
+    if task.creator != *creator_info.key {
+        return Err(BountyBoardError::NotCreator.into());
+    }
+
+    // Verify Slot PDA
+    let (slot_pda_key, _bump) = slot_pda(task.id, args.index());
+    if *slot_info.key != slot_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let slot = *slot_info.as_account::<Slot>(&bountyboard_api::ID)?;
+    if slot.status != SLOT_SUBMITTED {
+        return Err(BountyBoardError::InvalidSlot.into());
+    }
+    if slot.claimer != *claimer_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Rejections are capped at the slot count
+    if task.slots_rejected >= task.slots {
+        return Err(BountyBoardError::SlotRejectionLimit.into());
+    }
+    task.slots_rejected = task
+        .slots_rejected
+        .checked_add(1)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    // Update claimer reputation
+    if let Some(claimer_agent) = load_agent(claimer_agent_info, &slot.claimer)? {
+        claimer_agent.tasks_rejected = claimer_agent
+            .tasks_rejected
+            .checked_add(1)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    // Return the claim bond and the slot's rent, and free the slot
+    task_info.send(task.claim_bond, claimer_info);
+    slot_info.close(claimer_info)?;
+    task.slots_taken = task
+        .slots_taken
+        .checked_sub(1)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    solana_program::msg!("BountyBoard: Task {} slot {} rejected", task.id, slot.index);
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use solana_program::{
     account_info::AccountInfo,
+    clock::Clock,
     entrypoint::ProgramResult,
     program_error::ProgramError,
+    sysvar::Sysvar,
 };
 use steel::*;
 use bountyboard_api::{
@@ -11,15 +13,19 @@ use bountyboard_api::{
     state::Task,
 };
 
+use crate::reputation::load_agent;
+
 /// Process RejectWork instruction
 ///
-/// Creator rejects submitted work. Task goes back to OPEN, claimer removed.
+/// Creator rejects submitted work. Task moves to REJECTED and keeps its claimer,
+/// who may dispute within DISPUTE_WINDOW. Afterwards ReopenTask returns it to OPEN.
 ///
 /// Accounts:
 /// 0. `[signer]` Creator wallet
 /// 1. `[writable]` Task PDA
+/// 2. `[writable]` Claimer Agent PDA (skipped if unregistered)
 pub fn process_reject_work(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
-    let [creator_info, task_info] = accounts else {
+    let [creator_info, task_info, claimer_agent_info] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -40,12 +46,24 @@ pub fn process_reject_work(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
         return Err(BountyBoardError::NotCreator.into());
     }
 
-    // Reset task to open
-    task.claimer = solana_program::pubkey::Pubkey::default();
-    task.proof_hash = [0u8; 32];
-    task.status = STATUS_OPEN;
+    // Milestone tasks are rejected per milestone
+    if task.has_milestones() {
+        return Err(BountyBoardError::HasMilestones.into());
+    }
+
+    // Update claimer reputation
+    if let Some(claimer_agent) = load_agent(claimer_agent_info, &task.claimer)? {
+        claimer_agent.tasks_rejected = claimer_agent
+            .tasks_rejected
+            .checked_add(1)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    // Hold the task for the dispute window (claimer and proof are kept)
+    task.rejected_at = Clock::get()?.unix_timestamp;
+    task.status = STATUS_REJECTED;
 
-    solana_program::msg!("BountyBoard: Task {} rejected, returned to OPEN", task.id);
+    solana_program::msg!("BountyBoard: Task {} rejected, dispute window open", task.id);
 
     Ok(())
 }
@@ -0,0 +1,91 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::ReleaseStaleClaimArgs,
+    state::{Config, Task},
+};
+
+/// Process ReleaseStaleClaim instruction
+///
+/// Permissionless: if a claimer has not submitted within the task's work window
+/// (or the Config default), anyone can return the task to OPEN. The window runs
+/// from the claim, or from the last milestone submission on milestone tasks.
+/// The stale claimer's claim bond is forfeited to the creator.
+///
+/// Accounts:
+/// 0. `[signer]` Caller (anyone — permissionless)
+/// 1. `[]` Config PDA (read default_work_window)
+/// 2. `[writable]` Task PDA
+/// 3. `[writable]` Creator wallet (receives the claim bond)
+pub fn process_release_stale_claim(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [caller_info, config_info, task_info, creator_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let _args = bytemuck::try_from_bytes::<ReleaseStaleClaimArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    caller_info.is_signer()?;
+
+    let config = config_info.as_account::<Config>(&bountyboard_api::ID)?;
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Must be claimed (no pending submission)
+    if task.status != STATUS_CLAIMED {
+        return Err(BountyBoardError::InvalidTaskStatus.into());
+    }
+
+    let work_window = if task.work_window > 0 {
+        task.work_window
+    } else {
+        config.default_work_window
+    };
+
+    // A zero window means claims never go stale
+    if work_window == 0 {
+        return Err(BountyBoardError::ClaimNotStale.into());
+    }
+
+    let started_at = task.claimed_at.max(task.submitted_at);
+    let elapsed = Clock::get()?
+        .unix_timestamp
+        .checked_sub(started_at)
+        .ok_or(BountyBoardError::Overflow)?;
+    if elapsed < work_window {
+        return Err(BountyBoardError::ClaimNotStale.into());
+    }
+
+    if task.creator != *creator_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let stale_claimer = task.claimer;
+
+    // Claim bond forfeited to the creator
+    task_info.send(task.claim_bond, creator_info);
+
+    // Reset task to open
+    task.claimer = Pubkey::default();
+    task.proof_hash = [0u8; 32];
+    task.claimed_at = 0;
+    task.submitted_at = 0;
+    task.status = STATUS_OPEN;
+
+    solana_program::msg!(
+        "BountyBoard: Task {} released from stale claim by {} after {}s",
+        task.id,
+        stale_claimer,
+        elapsed
+    );
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::ReleaseStaleSlotArgs,
+    state::{Config, Slot, Task},
+};
+
+/// Process ReleaseStaleSlot instruction
+///
+/// Permissionless: if a slot's claimer has not submitted within the task's work
+/// window (or the Config default), or can no longer submit because the deadline
+/// and grace period have passed, anyone can free the slot.
+/// The Slot PDA is closed (rent back to the claimer) and the stale claimer's claim
+/// bond is forfeited to the creator. On a cancelled or expired task the freed slot
+/// is refunded by the next CancelTask / ExpireTask instead.
+///
+/// Accounts:
+/// 0. `[signer]` Caller (anyone — permissionless)
+/// 1. `[]` Config PDA (read default_work_window)
+/// 2. `[writable]` Task PDA
+/// 3. `[writable]` Slot PDA (closed)
+/// 4. `[writable]` Slot claimer wallet (receives slot rent)
+/// 5. `[writable]` Creator wallet (receives the claim bond)
+pub fn process_release_stale_slot(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [caller_info, config_info, task_info, slot_info, claimer_info, creator_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<ReleaseStaleSlotArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    caller_info.is_signer()?;
+
+    let config = config_info.as_account::<Config>(&bountyboard_api::ID)?;
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    if task.creator != *creator_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Verify Slot PDA
+    let (slot_pda_key, _bump) = slot_pda(task.id, args.index());
+    if *slot_info.key != slot_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let slot = *slot_info.as_account::<Slot>(&bountyboard_api::ID)?;
+    if slot.status != SLOT_CLAIMED {
+        return Err(BountyBoardError::InvalidSlot.into());
+    }
+    if slot.claimer != *claimer_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let work_window = if task.work_window > 0 {
+        task.work_window
+    } else {
+        config.default_work_window
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    let elapsed = now
+        .checked_sub(slot.claimed_at)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    // A zero window means claims only go stale once submissions close
+    let window_lapsed = work_window > 0 && elapsed >= work_window;
+    if !window_lapsed && !task.submission_closed(now) {
+        return Err(BountyBoardError::ClaimNotStale.into());
+    }
+
+    // Claim bond forfeited to the creator; the slot's rent goes back to its claimer
+    task_info.send(task.claim_bond, creator_info);
+    slot_info.close(claimer_info)?;
+    task.slots_taken = task
+        .slots_taken
+        .checked_sub(1)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    solana_program::msg!(
+        "BountyBoard: Task {} slot {} released from stale claim by {} after {}s",
+        task.id,
+        slot.index,
+        slot.claimer,
+        elapsed
+    );
+
+    Ok(())
+}
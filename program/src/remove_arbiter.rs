@@ -0,0 +1,68 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::RemoveArbiterArgs,
+    state::{Arbiter, Config},
+};
+
+/// Process RemoveArbiter instruction
+///
+/// Admin removes an arbiter. The last arbiter in the registry moves into the
+/// freed index and its PDA is closed (rent to the admin). Panels already assigned
+/// to open disputes are unaffected.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Admin wallet
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Arbiter PDA to remove
+/// 3. `[writable]` Last Arbiter PDA (same as 2 when removing the last one)
+pub fn process_remove_arbiter(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [admin_info, config_info, arbiter_info, last_arbiter_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<RemoveArbiterArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let index = args.index();
+
+    admin_info.is_signer()?;
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+
+    // Verify admin
+    if config.admin != *admin_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    if index >= config.arbiter_count {
+        return Err(BountyBoardError::InvalidArbiterCount.into());
+    }
+    let last = config.arbiter_count - 1;
+
+    if *arbiter_info.key != arbiter_pda(index).0 || *last_arbiter_info.key != arbiter_pda(last).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let removed = arbiter_info.as_account::<Arbiter>(&bountyboard_api::ID)?.wallet;
+
+    // Move the last arbiter into the freed index
+    if index != last {
+        let last_arbiter = *last_arbiter_info.as_account::<Arbiter>(&bountyboard_api::ID)?;
+        let arbiter = arbiter_info.as_account_mut::<Arbiter>(&bountyboard_api::ID)?;
+        arbiter.wallet = last_arbiter.wallet;
+        arbiter.added_at = last_arbiter.added_at;
+    }
+    last_arbiter_info.close(admin_info)?;
+
+    config.arbiter_count = last;
+
+    solana_program::msg!("BountyBoard: Arbiter {} removed", removed);
+
+    Ok(())
+}
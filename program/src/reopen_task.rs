@@ -0,0 +1,71 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::ReopenTaskArgs,
+    state::Task,
+};
+
+/// Process ReopenTask instruction
+///
+/// Permissionless: once DISPUTE_WINDOW has passed since a rejection without a
+/// dispute, anyone can return the task to OPEN so other agents can claim it.
+/// The rejected claimer delivered work, so their claim bond is returned.
+///
+/// Accounts:
+/// 0. `[signer]` Caller (anyone — permissionless)
+/// 1. `[writable]` Task PDA
+/// 2. `[writable]` Claimer wallet (receives the claim bond)
+pub fn process_reopen_task(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [caller_info, task_info, claimer_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let _args = bytemuck::try_from_bytes::<ReopenTaskArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    caller_info.is_signer()?;
+
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Must be rejected
+    if task.status != STATUS_REJECTED {
+        return Err(BountyBoardError::InvalidTaskStatus.into());
+    }
+
+    // Dispute window must have lapsed
+    let elapsed = Clock::get()?
+        .unix_timestamp
+        .checked_sub(task.rejected_at)
+        .ok_or(BountyBoardError::Overflow)?;
+    if elapsed <= DISPUTE_WINDOW {
+        return Err(BountyBoardError::DisputeWindowOpen.into());
+    }
+
+    if task.claimer != *claimer_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Return the claim bond
+    task_info.send(task.claim_bond, claimer_info);
+
+    // Reset task to open
+    task.claimer = Pubkey::default();
+    task.proof_hash = [0u8; 32];
+    task.claimed_at = 0;
+    task.submitted_at = 0;
+    task.rejected_at = 0;
+    task.status = STATUS_OPEN;
+
+    solana_program::msg!("BountyBoard: Task {} reopened after dispute window", task.id);
+
+    Ok(())
+}
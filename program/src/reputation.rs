@@ -0,0 +1,62 @@
+use solana_program::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    state::{Agent, Task},
+};
+
+/// Loads `wallet`'s Agent PDA for a reputation update.
+///
+/// Registration is optional, so an uninitialized Agent PDA yields `None` and the
+/// caller skips the update. The address itself is always verified.
+pub fn load_agent<'a>(
+    agent_info: &'a AccountInfo,
+    wallet: &Pubkey,
+) -> Result<Option<&'a mut Agent>, ProgramError> {
+    if *agent_info.key != agent_pda(wallet).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if agent_info.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(agent_info.as_account_mut::<Agent>(&bountyboard_api::ID)?))
+}
+
+/// Records a payout from `task`'s escrow: the creator spent `spent` and the claimer
+/// earned `payment`. Once the task is COMPLETED, it also counts as a completion for
+/// the claimer. Lamport totals skip token bounties.
+pub fn record_payout(
+    creator_agent_info: &AccountInfo,
+    claimer_agent_info: &AccountInfo,
+    task: &Task,
+    spent: u64,
+    payment: u64,
+) -> Result<(), ProgramError> {
+    let (spent, earned) = if task.is_token() { (0, 0) } else { (spent, payment) };
+    let completed = u64::from(task.status == STATUS_COMPLETED);
+
+    if let Some(creator_agent) = load_agent(creator_agent_info, &task.creator)? {
+        creator_agent.total_spent = creator_agent
+            .total_spent
+            .checked_add(spent)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    if let Some(claimer_agent) = load_agent(claimer_agent_info, &task.claimer)? {
+        claimer_agent.tasks_completed = claimer_agent
+            .tasks_completed
+            .checked_add(completed)
+            .ok_or(BountyBoardError::Overflow)?;
+        claimer_agent.total_earned = claimer_agent
+            .total_earned
+            .checked_add(earned)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    Ok(())
+}
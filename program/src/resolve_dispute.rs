@@ -1,33 +1,68 @@
 use solana_program::{
     account_info::AccountInfo,
+    clock::Clock,
     entrypoint::ProgramResult,
     program_error::ProgramError,
+    sysvar::Sysvar,
 };
 use steel::*;
 use bountyboard_api::{
     consts::*,
     error::BountyBoardError,
     instruction::ResolveDisputeArgs,
-    state::{Config, Task},
+    state::{Config, Dispute, Task},
 };
 
+use crate::settlement::{settle_dispute, DisputeAccounts};
+
 /// Process ResolveDispute instruction
 ///
-/// Admin resolves a disputed task.
-/// - winner=0: Creator wins → bounty refunded to creator, dispute stake to treasury
-/// - winner=1: Claimer wins → bounty+stake to claimer (minus protocol fee), fee to treasury
+/// Admin resolves a contested (counter-staked) dispute that has no arbiter panel,
+/// or whose panel ended its voting period without a decision, by awarding the
+/// claimer `claimer_share_bps` of the bounty:
+/// - 0: Creator wins → bounty and claim bond to creator, dispute stake to treasury
+/// - 10000: Claimer wins → bounty+stake+bond to claimer (minus protocol fee),
+///   fee to treasury
+/// - In between: bounty split pro rata, fee only on the claimer's share, stake and
+///   bond returned to the claimer in the same proportion
+///
+/// The dispute stake is always SOL; only the bounty follows the task's mint.
+/// For milestone tasks, only the unreleased remainder of the bounty is at stake.
 ///
 /// Accounts:
 /// 0. `[signer]` Admin wallet
-/// 1. `[]` Config PDA
+/// 1. `[writable]` Config PDA
 /// 2. `[writable]` Task PDA
-/// 3. `[writable]` Claimer wallet
-/// 4. `[writable]` Creator wallet
-/// 5. `[writable]` Treasury PDA
-/// 6. `[]` System program
+/// 3. `[writable]` Dispute PDA (closed, rent to claimer)
+/// 4. `[writable]` Claimer wallet
+/// 5. `[writable]` Creator wallet
+/// 6. `[writable]` Treasury PDA
+/// 7. `[]` System program
+/// 8. `[writable]` Creator Agent PDA (skipped if unregistered)
+/// 9. `[writable]` Claimer Agent PDA (skipped if unregistered)
+///
+/// Token bounties only:
+/// 10. `[]` Mint
+/// 11. `[writable]` Escrow token account
+/// 12. `[]` Token program
+/// 13. `[]` Associated token program
+/// 14.. `[writable]` Claimer then treasury token accounts if the share is non-zero,
+///       then the creator token account if the share is below 10000
+///       (ATAs, created if missing)
 pub fn process_resolve_dispute(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
-    let [admin_info, config_info, task_info, claimer_info, creator_info, treasury_info, _system_program] =
-        accounts
+    let [
+        admin_info,
+        config_info,
+        task_info,
+        dispute_info,
+        claimer_info,
+        creator_info,
+        treasury_info,
+        system_program,
+        creator_agent_info,
+        claimer_agent_info,
+        token_accounts @ ..,
+    ] = accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -37,7 +72,7 @@ pub fn process_resolve_dispute(accounts: &[AccountInfo], data: &[u8]) -> Program
 
     admin_info.is_signer()?;
 
-    let config = config_info.as_account::<Config>(&bountyboard_api::ID)?;
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
 
     // Verify admin
     if config.admin != *admin_info.key {
@@ -51,63 +86,42 @@ pub fn process_resolve_dispute(accounts: &[AccountInfo], data: &[u8]) -> Program
         return Err(BountyBoardError::TaskNotDisputed.into());
     }
 
-    // Verify accounts match task
-    if task.claimer != *claimer_info.key {
-        return Err(ProgramError::InvalidAccountData);
+    // Verify Dispute PDA
+    if *dispute_info.key != dispute_pda(task.id).0 {
+        return Err(ProgramError::InvalidSeeds);
     }
-    if task.creator != *creator_info.key {
-        return Err(ProgramError::InvalidAccountData);
+    let dispute = *dispute_info.as_account::<Dispute>(&bountyboard_api::ID)?;
+
+    // Default losses and panel decisions are settled by FinalizeDispute
+    let now = Clock::get()?.unix_timestamp;
+    if dispute.decision(now).is_some() {
+        return Err(BountyBoardError::DisputeDecided.into());
     }
-    if config.treasury != *treasury_info.key {
-        return Err(ProgramError::InvalidAccountData);
+    if !dispute.is_contested() {
+        return Err(BountyBoardError::CounterStakePending.into());
     }
 
-    let dispute_stake = config.dispute_stake;
-    let bounty = task.bounty;
-
-    match args.winner {
-        0 => {
-            // Creator wins: refund bounty to creator, dispute stake to treasury
-            **task_info.try_borrow_mut_lamports()? -= bounty;
-            **creator_info.try_borrow_mut_lamports()? += bounty;
-
-            **task_info.try_borrow_mut_lamports()? -= dispute_stake;
-            **treasury_info.try_borrow_mut_lamports()? += dispute_stake;
-
-            task.status = STATUS_CANCELLED;
-            solana_program::msg!("BountyBoard: Dispute resolved for task {} — creator wins", task.id);
-        }
-        1 => {
-            // Claimer wins: bounty (minus fee) + stake back to claimer, fee to treasury
-            let fee = bounty
-                .checked_mul(config.protocol_fee_bps as u64)
-                .ok_or(BountyBoardError::Overflow)?
-                .checked_div(TOTAL_BPS)
-                .ok_or(BountyBoardError::Overflow)?;
-
-            let payment = bounty
-                .checked_sub(fee)
-                .ok_or(BountyBoardError::Overflow)?;
-
-            // Payment + stake back to claimer
-            let claimer_total = payment
-                .checked_add(dispute_stake)
-                .ok_or(BountyBoardError::Overflow)?;
-
-            **task_info.try_borrow_mut_lamports()? -= claimer_total;
-            **claimer_info.try_borrow_mut_lamports()? += claimer_total;
-
-            // Fee to treasury
-            **task_info.try_borrow_mut_lamports()? -= fee;
-            **treasury_info.try_borrow_mut_lamports()? += fee;
-
-            task.status = STATUS_COMPLETED;
-            solana_program::msg!("BountyBoard: Dispute resolved for task {} — claimer wins", task.id);
-        }
-        _ => {
-            return Err(BountyBoardError::InvalidDisputeWinner.into());
-        }
+    // A panel decides unless it deadlocked past its voting period
+    if dispute.arbiter_count > 0 && now <= dispute.voting_deadline {
+        return Err(BountyBoardError::ArbiterPanelAssigned.into());
     }
 
-    Ok(())
+    settle_dispute(
+        &DisputeAccounts {
+            payer: admin_info,
+            task: task_info,
+            dispute: dispute_info,
+            claimer: claimer_info,
+            creator: creator_info,
+            treasury: treasury_info,
+            system_program,
+            creator_agent: creator_agent_info,
+            claimer_agent: claimer_agent_info,
+            token_accounts,
+        },
+        config,
+        task,
+        args.claimer_share_bps(),
+        &[],
+    )
 }
@@ -0,0 +1,175 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    state::{Config, Dispute, DisputeSplit, Task},
+};
+
+use crate::{
+    escrow::{refund_creator, Escrow},
+    reputation::record_payout,
+};
+
+/// Accounts shared by every instruction that settles a dispute.
+pub struct DisputeAccounts<'a, 'info> {
+    /// Signer paying for any token accounts created during payout
+    pub payer: &'a AccountInfo<'info>,
+    pub task: &'a AccountInfo<'info>,
+    pub dispute: &'a AccountInfo<'info>,
+    pub claimer: &'a AccountInfo<'info>,
+    pub creator: &'a AccountInfo<'info>,
+    pub treasury: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub creator_agent: &'a AccountInfo<'info>,
+    pub claimer_agent: &'a AccountInfo<'info>,
+    pub token_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Settles a disputed task by splitting it between creator and claimer (see
+/// `DisputeSplit`) and closes the Dispute PDA (rent back to the claimer who opened it).
+///
+/// - The claimer's share of the bounty, minus the protocol fee, goes to the claimer
+///   and the fee to the treasury; the rest of the bounty is refunded to the creator.
+/// - The claimer gets back the same share of their dispute stake and claim bond, and
+///   the creator forfeits that share of their counter-stake. The forfeited bond goes
+///   to the creator; forfeited stakes are split between `arbiters`
+///   (ARBITER_STAKE_SHARE_BPS, equally) and the treasury.
+///
+/// The task completes if the claimer receives any share, otherwise it is cancelled.
+/// Only the unreleased remainder of the bounty is at stake. The dispute stake is
+/// always SOL; only the bounty follows the task's mint.
+///
+/// Token tasks take the claimer then treasury token accounts when the share is
+/// non-zero, then the creator token account when it is below TOTAL_BPS.
+pub fn settle_dispute<'a, 'info>(
+    accounts: &DisputeAccounts<'a, 'info>,
+    config: &mut Config,
+    task: &mut Task,
+    claimer_share_bps: u16,
+    arbiters: &[&'a AccountInfo<'info>],
+) -> ProgramResult {
+    // Verify accounts match task
+    if task.claimer != *accounts.claimer.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if task.creator != *accounts.creator.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if config.treasury != *accounts.treasury.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if claimer_share_bps as u64 > TOTAL_BPS {
+        return Err(BountyBoardError::InvalidClaimerShare.into());
+    }
+
+    let dispute = *accounts.dispute.as_account::<Dispute>(&bountyboard_api::ID)?;
+    let bounty = task.remaining();
+    let split = DisputeSplit::new(
+        bounty,
+        dispute.stake,
+        dispute.creator_stake(),
+        task.claim_bond,
+        claimer_share_bps,
+        config.protocol_fee_bps,
+    )
+    .ok_or(BountyBoardError::Overflow)?;
+
+    let mut escrow = Escrow::load(
+        accounts.task,
+        task,
+        accounts.payer,
+        accounts.system_program,
+        accounts.token_accounts,
+    )?;
+
+    // Claimer's share: payment to claimer, fee to treasury
+    if claimer_share_bps > 0 {
+        let claimer_destination = escrow.recipient(accounts.claimer)?;
+        let treasury_destination = escrow.recipient(accounts.treasury)?;
+        escrow.release(claimer_destination, split.claimer_payment)?;
+        escrow.release(treasury_destination, split.fee)?;
+        if !task.is_token() {
+            config.total_fees_collected = config
+                .total_fees_collected
+                .checked_add(split.fee)
+                .ok_or(BountyBoardError::Overflow)?;
+        }
+    }
+
+    // Creator's share: refund to creator and contributors
+    if claimer_share_bps as u64 != TOTAL_BPS {
+        refund_creator(&mut escrow, task, accounts.creator, split.creator_refund)?;
+    }
+
+    // Stakes and claim bond back in proportion, forfeited bond to creator
+    accounts
+        .task
+        .send(split.stake_returned + split.bond_returned, accounts.claimer);
+    accounts
+        .task
+        .send(split.creator_stake_returned + split.bond_forfeited, accounts.creator);
+
+    // Forfeited stakes: arbiters' share, remainder to treasury
+    let forfeited = split
+        .stake_forfeited
+        .checked_add(split.creator_stake_forfeited)
+        .ok_or(BountyBoardError::Overflow)?;
+    let arbiter_pay = if arbiters.is_empty() {
+        0
+    } else {
+        forfeited
+            .checked_mul(ARBITER_STAKE_SHARE_BPS)
+            .ok_or(BountyBoardError::Overflow)?
+            .checked_div(TOTAL_BPS)
+            .ok_or(BountyBoardError::Overflow)?
+            .checked_div(arbiters.len() as u64)
+            .ok_or(BountyBoardError::Overflow)?
+    };
+    for arbiter in arbiters {
+        accounts.task.send(arbiter_pay, arbiter);
+    }
+    let to_treasury = forfeited
+        .checked_sub(arbiter_pay * arbiters.len() as u64)
+        .ok_or(BountyBoardError::Overflow)?;
+    accounts.task.send(to_treasury, accounts.treasury);
+    config.total_fees_collected = config
+        .total_fees_collected
+        .checked_add(to_treasury)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    if claimer_share_bps > 0 {
+        task.status = STATUS_COMPLETED;
+
+        // Update creator and claimer reputation
+        let spent = split
+            .claimer_payment
+            .checked_add(split.fee)
+            .ok_or(BountyBoardError::Overflow)?;
+        record_payout(accounts.creator_agent, accounts.claimer_agent, task, spent, split.claimer_payment)?;
+    } else {
+        task.status = STATUS_CANCELLED;
+    }
+
+    // The whole remainder has left escrow, to either side
+    if !task.is_token() {
+        config.total_escrowed = config
+            .total_escrowed
+            .checked_sub(bounty)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    accounts.dispute.close(accounts.claimer)?;
+
+    solana_program::msg!(
+        "BountyBoard: Dispute resolved for task {} — claimer share {} bps",
+        task.id,
+        claimer_share_bps
+    );
+
+    Ok(())
+}
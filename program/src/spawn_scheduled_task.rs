@@ -0,0 +1,138 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::{CreateTaskArgs, SpawnScheduledTaskArgs},
+    state::{Config, Task, Vault},
+};
+
+use crate::{create_task::init_task, reputation::load_agent, tags::validate_tags};
+
+/// Process SpawnScheduledTask instruction
+///
+/// Permissionless crank: once a vault's next spawn time arrives, anyone can post
+/// its task. The vault pays the task's rent and bounty, and the vault owner
+/// becomes the task's creator. Missed intervals are not made up: if the crank
+/// runs late, the next spawn is one interval from now. The vault's tags are
+/// checked again, so a category deactivated since CreateVault stops the spawns.
+///
+/// Accounts:
+/// 0. `[signer]` Caller (anyone)
+/// 1. `[writable]` Config PDA
+/// 2. `[writable]` Vault PDA
+/// 3. `[writable]` Task PDA (to be created)
+/// 4. `[]` System program
+/// 5. `[writable]` Owner Agent PDA (skipped if unregistered)
+/// 6. `[]` Category PDA of the vault's category (ignored if uncategorized)
+pub fn process_spawn_scheduled_task(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [caller_info, config_info, vault_info, task_info, system_program, owner_agent_info, category_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<SpawnScheduledTaskArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    caller_info.is_signer()?;
+
+    if *vault_info.key != vault_pda(args.vault_id()).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+    let vault = vault_info.as_account_mut::<Vault>(&bountyboard_api::ID)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if now < vault.next_spawn_at {
+        return Err(BountyBoardError::ScheduleNotDue.into());
+    }
+
+    // Same tag rules as CreateTask
+    validate_tags(&vault.tags, category_info)?;
+
+    let task_id = config.task_count;
+    let (task_pda_key, _task_bump) = task_pda(task_id);
+    if *task_info.key != task_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // The vault covers the Task PDA's rent as well as the bounty
+    let rent = Rent::get()?.minimum_balance(8 + std::mem::size_of::<Task>());
+    let cost = vault
+        .bounty
+        .checked_add(rent)
+        .ok_or(BountyBoardError::Overflow)?;
+    vault.balance = vault
+        .balance
+        .checked_sub(cost)
+        .ok_or(BountyBoardError::InsufficientVault)?;
+
+    // Prefunded, so creating the account needs nothing from the payer
+    vault_info.send(cost, task_info);
+    create_program_account::<Task>(
+        task_info,
+        system_program,
+        vault_info,
+        &bountyboard_api::ID,
+        &[TASK, &task_id.to_le_bytes()],
+    )?;
+
+    let deadline = if vault.deadline_offset > 0 {
+        now.checked_add(vault.deadline_offset)
+            .ok_or(BountyBoardError::Overflow)?
+    } else {
+        0
+    };
+    let task_args = CreateTaskArgs::new(vault.bounty, vault.description_hash, deadline, vault.tags)
+        .with_work_window(vault.work_window)
+        .with_claim_bond(vault.claim_bond)
+        .with_flags(vault.flags)
+        .with_min_reputation(vault.min_completions, vault.min_approval_bps);
+
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+    init_task(task, task_id, vault.owner, vault.bounty, Pubkey::default(), 0, &task_args)?;
+
+    config.task_count = task_id.checked_add(1).ok_or(BountyBoardError::Overflow)?;
+    config.total_escrowed = config
+        .total_escrowed
+        .checked_add(vault.bounty)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    if let Some(owner_agent) = load_agent(owner_agent_info, &vault.owner)? {
+        owner_agent.tasks_posted = owner_agent
+            .tasks_posted
+            .checked_add(1)
+            .ok_or(BountyBoardError::Overflow)?;
+    }
+
+    vault.spawned = vault.spawned.checked_add(1).ok_or(BountyBoardError::Overflow)?;
+    vault.last_task_id = task_id;
+    let next_spawn_at = vault
+        .next_spawn_at
+        .checked_add(vault.interval)
+        .ok_or(BountyBoardError::Overflow)?;
+    vault.next_spawn_at = if next_spawn_at > now {
+        next_spawn_at
+    } else {
+        now.checked_add(vault.interval).ok_or(BountyBoardError::Overflow)?
+    };
+
+    solana_program::msg!(
+        "BountyBoard: Vault {} spawned task {} with bounty {} lamports",
+        vault.id,
+        task_id,
+        vault.bounty
+    );
+
+    Ok(())
+}
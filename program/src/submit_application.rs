@@ -0,0 +1,99 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::SubmitApplicationArgs,
+    state::{Application, Task},
+};
+
+/// Process SubmitApplication instruction
+///
+/// Worker applies to an open application-mode task with a price quote, ETA and
+/// pitch hash. The task's claim bond (if any) is deposited with the application.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Applicant wallet
+/// 1. `[]` Task PDA
+/// 2. `[writable]` Application PDA (to be created)
+/// 3. `[]` System program
+pub fn process_submit_application(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [applicant_info, task_info, application_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<SubmitApplicationArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    applicant_info.is_signer()?;
+
+    let task = task_info.as_account::<Task>(&bountyboard_api::ID)?;
+
+    // Task must be open and in application mode
+    if task.status != STATUS_OPEN {
+        return Err(BountyBoardError::TaskNotOpen.into());
+    }
+    if !task.requires_application() {
+        return Err(BountyBoardError::ApplicationsClosed.into());
+    }
+
+    // Creator cannot apply to their own task
+    if task.creator == *applicant_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    // Quote must be non-zero and fit the escrowed bounty
+    let price = args.price();
+    if price == 0 || price > task.remaining() {
+        return Err(BountyBoardError::InvalidQuote.into());
+    }
+
+    // Check deadline hasn't passed (if set)
+    let clock = Clock::get()?;
+    if task.deadline > 0 && clock.unix_timestamp > task.deadline {
+        return Err(BountyBoardError::DeadlinePassed.into());
+    }
+
+    // Verify Application PDA
+    let (application_pda_key, _bump) = application_pda(task.id, applicant_info.key);
+    if *application_info.key != application_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    create_program_account::<Application>(
+        application_info,
+        system_program,
+        applicant_info,
+        &bountyboard_api::ID,
+        &[APPLICATION, &task.id.to_le_bytes(), applicant_info.key.as_ref()],
+    )?;
+
+    // Deposit the claim bond with the application
+    if task.claim_bond > 0 {
+        application_info.collect(task.claim_bond, applicant_info)?;
+    }
+
+    let application = application_info.as_account_mut::<Application>(&bountyboard_api::ID)?;
+    application.task_id = task.id;
+    application.applicant = *applicant_info.key;
+    application.price = price;
+    application.eta = args.eta();
+    application.pitch_hash = args.pitch_hash;
+    application.bond = task.claim_bond;
+    application.created_at = clock.unix_timestamp;
+
+    solana_program::msg!(
+        "BountyBoard: {} applied to task {} quoting {}",
+        applicant_info.key,
+        task.id,
+        price
+    );
+
+    Ok(())
+}
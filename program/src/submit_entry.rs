@@ -0,0 +1,82 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::SubmitEntryArgs,
+    state::{Submission, Task},
+};
+
+/// Process SubmitEntry instruction
+///
+/// Agent submits an entry to an open contest before its deadline. Each agent
+/// gets one Submission PDA per contest.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Entrant wallet
+/// 1. `[writable]` Task PDA
+/// 2. `[writable]` Submission PDA (to be created)
+/// 3. `[]` System program
+pub fn process_submit_entry(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [entrant_info, task_info, submission_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<SubmitEntryArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    entrant_info.is_signer()?;
+
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Must be an open contest
+    if !task.is_contest() {
+        return Err(BountyBoardError::NotContest.into());
+    }
+    if task.status != STATUS_OPEN {
+        return Err(BountyBoardError::TaskNotOpen.into());
+    }
+
+    // Creator cannot enter their own contest
+    if task.creator == *entrant_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    // Entries close at the deadline
+    let clock = Clock::get()?;
+    if clock.unix_timestamp > task.deadline {
+        return Err(BountyBoardError::DeadlinePassed.into());
+    }
+
+    // Verify Submission PDA
+    let (submission_pda_key, _bump) = submission_pda(task.id, entrant_info.key);
+    if *submission_info.key != submission_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    create_program_account::<Submission>(
+        submission_info,
+        system_program,
+        entrant_info,
+        &bountyboard_api::ID,
+        &[SUBMISSION, &task.id.to_le_bytes(), entrant_info.key.as_ref()],
+    )?;
+
+    let submission = submission_info.as_account_mut::<Submission>(&bountyboard_api::ID)?;
+    submission.task_id = task.id;
+    submission.entrant = *entrant_info.key;
+    submission.proof_hash = args.proof_hash;
+    submission.submitted_at = clock.unix_timestamp;
+
+    task.entries = task.entries.checked_add(1).ok_or(BountyBoardError::Overflow)?;
+
+    solana_program::msg!("BountyBoard: {} entered contest {}", entrant_info.key, task.id);
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::SubmitEvidenceArgs,
+    state::Dispute,
+};
+
+/// Process SubmitEvidence instruction
+///
+/// The creator or claimer attaches an evidence hash (e.g. of an IPFS bundle) to
+/// the Dispute PDA for arbiters to review. Each party has one slot; resubmitting
+/// replaces it. Evidence is accepted until the voting deadline.
+///
+/// Accounts:
+/// 0. `[signer]` Creator or claimer wallet
+/// 1. `[writable]` Dispute PDA
+pub fn process_submit_evidence(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [party_info, dispute_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<SubmitEvidenceArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    party_info.is_signer()?;
+
+    // Verify Dispute PDA
+    if *dispute_info.key != dispute_pda(args.task_id()).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let dispute = dispute_info.as_account_mut::<Dispute>(&bountyboard_api::ID)?;
+
+    if Clock::get()?.unix_timestamp > dispute.voting_deadline {
+        return Err(BountyBoardError::VotingClosed.into());
+    }
+
+    if *party_info.key == dispute.creator {
+        dispute.creator_evidence = args.evidence_hash;
+    } else if *party_info.key == dispute.claimer {
+        dispute.claimer_evidence = args.evidence_hash;
+    } else {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    solana_program::msg!(
+        "BountyBoard: Evidence submitted on task {} by {}",
+        dispute.task_id,
+        party_info.key
+    );
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::SubmitMilestoneArgs,
+    state::{Milestone, Task},
+};
+
+/// Process SubmitMilestone instruction
+///
+/// Claimer submits proof for the next unreleased milestone. The milestone and
+/// the task both move to SUBMITTED until the creator approves or rejects it.
+/// Submissions close at the task deadline (if set) plus DEADLINE_GRACE_PERIOD.
+///
+/// Accounts:
+/// 0. `[signer]` Claimer wallet
+/// 1. `[writable]` Task PDA
+/// 2. `[writable]` Milestone PDA
+pub fn process_submit_milestone(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [claimer_info, task_info, milestone_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<SubmitMilestoneArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    claimer_info.is_signer()?;
+
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Must be claimed
+    if task.status != STATUS_CLAIMED {
+        return Err(BountyBoardError::InvalidTaskStatus.into());
+    }
+
+    // Only claimer can submit
+    if task.claimer != *claimer_info.key {
+        return Err(BountyBoardError::NotClaimer.into());
+    }
+
+    // Milestones are submitted in order
+    if !task.has_milestones() || args.index != task.milestones_released {
+        return Err(BountyBoardError::InvalidMilestone.into());
+    }
+
+    // Verify milestone PDA
+    let (milestone_pda_key, _milestone_bump) = milestone_pda(task.id, args.index);
+    if *milestone_info.key != milestone_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let milestone = milestone_info.as_account_mut::<Milestone>(&bountyboard_api::ID)?;
+    if milestone.status != MILESTONE_PENDING {
+        return Err(BountyBoardError::InvalidMilestone.into());
+    }
+
+    // Reject late work
+    let now = Clock::get()?.unix_timestamp;
+    if task.submission_closed(now) {
+        return Err(BountyBoardError::DeadlinePassed.into());
+    }
+
+    // Record proof on both the milestone and the task
+    milestone.proof_hash = args.proof_hash;
+    milestone.submitted_at = now;
+    milestone.status = MILESTONE_SUBMITTED;
+
+    task.proof_hash = args.proof_hash;
+    task.submitted_at = now;
+    task.status = STATUS_SUBMITTED;
+
+    solana_program::msg!("BountyBoard: Task {} milestone {} submitted", task.id, args.index);
+
+    Ok(())
+}
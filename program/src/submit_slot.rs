@@ -0,0 +1,66 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::SubmitSlotArgs,
+    state::{Slot, Task},
+};
+
+/// Process SubmitSlot instruction
+///
+/// Slot claimer submits proof of work for their slot, before the task deadline
+/// plus DEADLINE_GRACE_PERIOD.
+///
+/// Accounts:
+/// 0. `[signer]` Claimer wallet
+/// 1. `[]` Task PDA
+/// 2. `[writable]` Slot PDA
+pub fn process_submit_slot(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [claimer_info, task_info, slot_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<SubmitSlotArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    claimer_info.is_signer()?;
+
+    let task = task_info.as_account::<Task>(&bountyboard_api::ID)?;
+
+    // Verify Slot PDA
+    let (slot_pda_key, _bump) = slot_pda(task.id, args.index());
+    if *slot_info.key != slot_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let slot = slot_info.as_account_mut::<Slot>(&bountyboard_api::ID)?;
+    if slot.status != SLOT_CLAIMED {
+        return Err(BountyBoardError::InvalidSlot.into());
+    }
+
+    // Only the slot's claimer can submit
+    if slot.claimer != *claimer_info.key {
+        return Err(BountyBoardError::NotClaimer.into());
+    }
+
+    // Reject late work
+    let now = Clock::get()?.unix_timestamp;
+    if task.submission_closed(now) {
+        return Err(BountyBoardError::DeadlinePassed.into());
+    }
+
+    slot.proof_hash = args.proof_hash;
+    slot.submitted_at = now;
+    slot.status = SLOT_SUBMITTED;
+
+    solana_program::msg!("BountyBoard: Task {} slot {} submitted", task.id, slot.index);
+
+    Ok(())
+}
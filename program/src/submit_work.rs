@@ -16,6 +16,8 @@ use bountyboard_api::{
 /// Process SubmitWork instruction
 ///
 /// Claimer submits proof hash. Task moves to SUBMITTED.
+/// Work is rejected once the task deadline (if set) plus DEADLINE_GRACE_PERIOD
+/// has passed.
 ///
 /// Accounts:
 /// 0. `[signer]` Claimer wallet
@@ -42,9 +44,20 @@ pub fn process_submit_work(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
         return Err(BountyBoardError::NotClaimer.into());
     }
 
+    // Milestone tasks are submitted per milestone
+    if task.has_milestones() {
+        return Err(BountyBoardError::HasMilestones.into());
+    }
+
+    // Reject late work
+    let now = Clock::get()?.unix_timestamp;
+    if task.submission_closed(now) {
+        return Err(BountyBoardError::DeadlinePassed.into());
+    }
+
     // Record proof, timestamp, and update status
     task.proof_hash = args.proof_hash;
-    task.submitted_at = Clock::get()?.unix_timestamp;
+    task.submitted_at = now;
     task.status = STATUS_SUBMITTED;
 
     solana_program::msg!("BountyBoard: Task {} work submitted", task.id);
@@ -0,0 +1,38 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    state::Category,
+    tags::Tags,
+};
+
+/// Validates a task's encoded `tags`: known flags, packed unique skills, and a
+/// category that is either 0 (uncategorized) or an active Category PDA passed
+/// as `category_info`.
+pub fn validate_tags(tags: &[u8; 16], category_info: &AccountInfo) -> ProgramResult {
+    let tags = Tags::decode(tags);
+    if !tags.is_valid() {
+        return Err(BountyBoardError::InvalidTags.into());
+    }
+
+    if tags.category == 0 {
+        return Ok(());
+    }
+    if *category_info.key != category_pda(tags.category).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if category_info.data_is_empty() {
+        return Err(BountyBoardError::InvalidCategory.into());
+    }
+    let category = category_info.as_account::<Category>(&bountyboard_api::ID)?;
+    if !category.is_active() {
+        return Err(BountyBoardError::InvalidCategory.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::UpdateCategoryArgs,
+    state::{Category, Config},
+};
+
+/// Process UpdateCategory instruction
+///
+/// Admin renames a category or toggles whether new tasks may use it. Existing
+/// tasks keep their category either way.
+///
+/// Accounts:
+/// 0. `[signer]` Admin wallet
+/// 1. `[]` Config PDA
+/// 2. `[writable]` Category PDA
+pub fn process_update_category(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [admin_info, config_info, category_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<UpdateCategoryArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    admin_info.is_signer()?;
+
+    let config = config_info.as_account::<Config>(&bountyboard_api::ID)?;
+
+    // Verify admin
+    if config.admin != *admin_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    if *category_info.key != category_pda(args.id()).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let category = category_info.as_account_mut::<Category>(&bountyboard_api::ID)?;
+    category.active = u8::from(args.active != 0);
+    category.name = args.name;
+
+    solana_program::msg!(
+        "BountyBoard: Category {} updated (active: {})",
+        category.id,
+        category.is_active()
+    );
+
+    Ok(())
+}
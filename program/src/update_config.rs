@@ -0,0 +1,73 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::UpdateConfigArgs,
+    state::Config,
+};
+
+/// Process UpdateConfig instruction
+///
+/// Admin updates the protocol fee, dispute stake, default work window, arbiter
+/// panel size and dispute resolution timeout.
+/// Changes apply to future payouts and disputes; in-flight disputes keep the
+/// stake and timeout they were opened with.
+///
+/// Accounts:
+/// 0. `[signer]` Admin wallet
+/// 1. `[writable]` Config PDA
+pub fn process_update_config(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [admin_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<UpdateConfigArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    admin_info.is_signer()?;
+
+    let config = config_info.as_account_mut::<Config>(&bountyboard_api::ID)?;
+
+    // Verify admin
+    if config.admin != *admin_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    // Fee cannot exceed 100%
+    if args.protocol_fee_bps() as u64 > TOTAL_BPS {
+        return Err(BountyBoardError::InvalidFee.into());
+    }
+
+    if args.default_work_window() < 0 {
+        return Err(BountyBoardError::InvalidWorkWindow.into());
+    }
+
+    if args.arbiters_per_dispute as usize > MAX_DISPUTE_ARBITERS {
+        return Err(BountyBoardError::InvalidArbiterCount.into());
+    }
+
+    // Panels must get their full voting period before a dispute can expire
+    if args.dispute_resolution_timeout() < DISPUTE_VOTING_PERIOD {
+        return Err(BountyBoardError::InvalidDisputeTimeout.into());
+    }
+
+    config.protocol_fee_bps = args.protocol_fee_bps();
+    config.dispute_stake = args.dispute_stake();
+    config.default_work_window = args.default_work_window();
+    config.arbiters_per_dispute = args.arbiters_per_dispute;
+    config.dispute_resolution_timeout = args.dispute_resolution_timeout();
+
+    solana_program::msg!("BountyBoard: Config updated");
+    solana_program::msg!("  Fee: {} bps", config.protocol_fee_bps);
+    solana_program::msg!("  Dispute stake: {} lamports", config.dispute_stake);
+    solana_program::msg!("  Default work window: {}s", config.default_work_window);
+    solana_program::msg!("  Arbiters per dispute: {}", config.arbiters_per_dispute);
+    solana_program::msg!("  Dispute resolution timeout: {}s", config.dispute_resolution_timeout);
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::UpdateTaskArgs,
+    state::Task,
+};
+
+use crate::tags::validate_tags;
+
+/// Process UpdateTask instruction
+///
+/// Creator edits a task's description hash, tags and deadline.
+/// - Description and tags can only change before work has started: the task is
+///   OPEN, a contest has no entries and no slot is taken.
+/// - The deadline can change while OPEN or CLAIMED. A new deadline must be in the
+///   future (or 0 to remove it, except for contests). Once work has started it can
+///   only be extended.
+///
+/// Accounts:
+/// 0. `[signer]` Creator wallet
+/// 1. `[writable]` Task PDA
+/// 2. `[]` Category PDA of the new tags' category (ignored if uncategorized)
+pub fn process_update_task(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [creator_info, task_info, category_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<UpdateTaskArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    creator_info.is_signer()?;
+
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Only creator can edit
+    if task.creator != *creator_info.key {
+        return Err(BountyBoardError::NotCreator.into());
+    }
+
+    if task.status != STATUS_OPEN && task.status != STATUS_CLAIMED {
+        return Err(BountyBoardError::InvalidTaskStatus.into());
+    }
+
+    // Workers accepted the task as described
+    let work_started = task.status == STATUS_CLAIMED || task.entries > 0 || task.slots_taken > 0;
+    let details_changed = task.description_hash != args.description_hash || task.tags != args.tags;
+    if details_changed && work_started {
+        return Err(BountyBoardError::TaskNotOpen.into());
+    }
+    if task.tags != args.tags {
+        validate_tags(&args.tags, category_info)?;
+    }
+
+    let deadline = args.deadline();
+    if deadline != task.deadline {
+        let now = Clock::get()?.unix_timestamp;
+        if deadline != 0 && deadline <= now {
+            return Err(BountyBoardError::InvalidDeadline.into());
+        }
+        if deadline == 0 && task.is_contest() {
+            return Err(BountyBoardError::InvalidDeadline.into());
+        }
+
+        let extends = deadline == 0 || (task.deadline != 0 && deadline > task.deadline);
+        if work_started && !extends {
+            return Err(BountyBoardError::InvalidDeadline.into());
+        }
+    }
+
+    task.description_hash = args.description_hash;
+    task.tags = args.tags;
+    task.deadline = deadline;
+
+    solana_program::msg!("BountyBoard: Task {} updated, deadline {}", task.id, task.deadline);
+
+    Ok(())
+}
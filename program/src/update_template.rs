@@ -0,0 +1,56 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::UpdateTemplateArgs,
+    state::Template,
+};
+
+use crate::create_template::{validate_template, write_template};
+
+/// Process UpdateTemplate instruction
+///
+/// Template owner replaces the template's settings and can hand the template
+/// to a new owner. Tasks already created from it are unaffected.
+///
+/// Accounts:
+/// 0. `[signer]` Owner wallet
+/// 1. `[writable]` Template PDA
+/// 2. `[]` Category PDA of the tags' category (ignored if uncategorized)
+pub fn process_update_template(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [owner_info, template_info, category_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<UpdateTemplateArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    owner_info.is_signer()?;
+
+    if *template_info.key != template_pda(args.template_id()).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let template = template_info.as_account_mut::<Template>(&bountyboard_api::ID)?;
+    if template.owner != *owner_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    validate_template(&args.template, category_info)?;
+    write_template(template, &args.template)?;
+
+    let new_owner = args.new_owner();
+    if new_owner != Pubkey::default() {
+        template.owner = new_owner;
+    }
+
+    solana_program::msg!("BountyBoard: Template {} updated", template.id);
+
+    Ok(())
+}
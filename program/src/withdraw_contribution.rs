@@ -0,0 +1,113 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::WithdrawContributionArgs,
+    state::{Contribution, Task},
+};
+
+use crate::escrow::Escrow;
+
+/// Process WithdrawContribution instruction
+///
+/// Once a task is finished, a funder closes their Contribution PDA and receives
+/// their pro-rata share of the refund pool (contribution / outstanding
+/// contributions, rounded down; the last funder takes whatever is left so nothing
+/// is stranded). Completed tasks with no refund just return the PDA's rent.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Funder wallet (receives refund and rent)
+/// 1. `[writable]` Task PDA
+/// 2. `[writable]` Contribution PDA (closed)
+/// 3. `[]` System program
+///
+/// Token bounties only:
+/// 4. `[]` Mint
+/// 5. `[writable]` Escrow token account
+/// 6. `[]` Token program
+/// 7. `[]` Associated token program
+/// 8. `[writable]` Funder token account (ATA, created if missing)
+pub fn process_withdraw_contribution(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [funder_info, task_info, contribution_info, system_program, token_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let _args = bytemuck::try_from_bytes::<WithdrawContributionArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    funder_info.is_signer()?;
+
+    let task = task_info.as_account_mut::<Task>(&bountyboard_api::ID)?;
+
+    // Only finished tasks pay out refunds
+    if task.status != STATUS_COMPLETED && task.status != STATUS_CANCELLED {
+        return Err(BountyBoardError::InvalidTaskStatus.into());
+    }
+
+    let mut escrow = Escrow::load(task_info, task, funder_info, system_program, token_accounts)?;
+    settle_contribution(&mut escrow, task, contribution_info, funder_info)?;
+
+    Ok(())
+}
+
+/// Pays `funder_info` its pro-rata share of a finished task's refund pool and
+/// closes its Contribution PDA, returning the rent; shared by WithdrawContribution
+/// and CloseTask.
+pub fn settle_contribution<'a, 'info>(
+    escrow: &mut Escrow<'a, 'info>,
+    task: &mut Task,
+    contribution_info: &'a AccountInfo<'info>,
+    funder_info: &'a AccountInfo<'info>,
+) -> ProgramResult {
+    // Verify Contribution PDA
+    let (contribution_pda_key, _bump) = contribution_pda(task.id, funder_info.key);
+    if *contribution_info.key != contribution_pda_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let amount = contribution_info
+        .as_account::<Contribution>(&bountyboard_api::ID)?
+        .amount;
+
+    let refund = if task.contributors == 1 {
+        task.refund_pool
+    } else {
+        let share = (task.refund_pool as u128)
+            .checked_mul(amount as u128)
+            .ok_or(BountyBoardError::Overflow)?
+            .checked_div(task.contributed_open as u128)
+            .ok_or(BountyBoardError::Overflow)?;
+        u64::try_from(share).map_err(|_| BountyBoardError::Overflow)?
+    };
+
+    let funder_destination = escrow.recipient(funder_info)?;
+    escrow.release(funder_destination, refund)?;
+
+    task.refund_pool = task
+        .refund_pool
+        .checked_sub(refund)
+        .ok_or(BountyBoardError::Overflow)?;
+    task.contributed_open = task
+        .contributed_open
+        .checked_sub(amount)
+        .ok_or(BountyBoardError::Overflow)?;
+    task.contributors = task
+        .contributors
+        .checked_sub(1)
+        .ok_or(BountyBoardError::Overflow)?;
+
+    contribution_info.close(funder_info)?;
+
+    solana_program::msg!(
+        "BountyBoard: Contribution of {} to task {} withdrawn, refunded {}",
+        amount,
+        task.id,
+        refund
+    );
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::WithdrawTreasuryArgs,
+    state::{Config, Treasury},
+};
+
+use crate::escrow::{transfer_checked_signed, TokenEscrow};
+
+/// Process WithdrawTreasury instruction
+///
+/// Admin withdraws collected fees. Native withdrawals leave the treasury
+/// rent-exempt; token withdrawals pay out of the treasury's token account.
+///
+/// Accounts:
+/// 0. `[signer]` Admin wallet
+/// 1. `[]` Config PDA
+/// 2. `[writable]` Treasury PDA
+/// 3. `[writable]` Destination (wallet, or token account for token withdrawals)
+///
+/// Token withdrawals only:
+/// 4. `[]` Mint
+/// 5. `[writable]` Treasury token account
+/// 6. `[]` Token program
+/// 7. `[]` Associated token program
+pub fn process_withdraw_treasury(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [admin_info, config_info, treasury_info, destination_info, token_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<WithdrawTreasuryArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let amount = args.amount();
+
+    admin_info.is_signer()?;
+
+    let config = config_info.as_account::<Config>(&bountyboard_api::ID)?;
+
+    // Verify admin
+    if config.admin != *admin_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    // Verify treasury
+    if config.treasury != *treasury_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if amount == 0 {
+        return Err(BountyBoardError::InsufficientTreasury.into());
+    }
+
+    match token_accounts {
+        [] => {
+            // Keep the treasury rent-exempt
+            let rent_exempt = Rent::get()?.minimum_balance(treasury_info.data_len());
+            let available = treasury_info.lamports().saturating_sub(rent_exempt);
+            if amount > available {
+                return Err(BountyBoardError::InsufficientTreasury.into());
+            }
+
+            let treasury = treasury_info.as_account_mut::<Treasury>(&bountyboard_api::ID)?;
+            treasury.total_withdrawn = treasury
+                .total_withdrawn
+                .checked_add(amount)
+                .ok_or(BountyBoardError::Overflow)?;
+
+            treasury_info.send(amount, destination_info);
+
+            solana_program::msg!("BountyBoard: Withdrew {} lamports from treasury", amount);
+        }
+        [mint_info, treasury_token_info, token_program, associated_token_program] => {
+            // Treasury must still be a valid Treasury account
+            treasury_info.as_account::<Treasury>(&bountyboard_api::ID)?;
+
+            let token = TokenEscrow::new(
+                treasury_info,
+                mint_info,
+                treasury_token_info,
+                token_program,
+                associated_token_program,
+            )?;
+
+            transfer_checked_signed(
+                treasury_info,
+                token.escrow,
+                token.mint,
+                destination_info,
+                token.token_program,
+                amount,
+                token.decimals,
+                &[TREASURY],
+            )?;
+
+            solana_program::msg!(
+                "BountyBoard: Withdrew {} of mint {} from treasury",
+                amount,
+                mint_info.key
+            );
+        }
+        _ => return Err(ProgramError::NotEnoughAccountKeys),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use steel::*;
+use bountyboard_api::{
+    consts::*,
+    error::BountyBoardError,
+    instruction::WithdrawVaultArgs,
+    state::Vault,
+};
+
+/// Process WithdrawVault instruction
+///
+/// Owner takes unspent SOL back out of a vault. Already spawned tasks keep
+/// their escrow.
+///
+/// Accounts:
+/// 0. `[signer, writable]` Owner wallet
+/// 1. `[writable]` Vault PDA
+pub fn process_withdraw_vault(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [owner_info, vault_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = bytemuck::try_from_bytes::<WithdrawVaultArgs>(data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let amount = args.amount();
+
+    owner_info.is_signer()?;
+
+    if *vault_info.key != vault_pda(args.vault_id()).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let vault = vault_info.as_account_mut::<Vault>(&bountyboard_api::ID)?;
+    if vault.owner != *owner_info.key {
+        return Err(BountyBoardError::Unauthorized.into());
+    }
+
+    vault.balance = vault
+        .balance
+        .checked_sub(amount)
+        .ok_or(BountyBoardError::InsufficientVault)?;
+    vault_info.send(amount, owner_info);
+
+    solana_program::msg!("BountyBoard: Withdrew {} lamports from vault {}", amount, args.vault_id());
+
+    Ok(())
+}
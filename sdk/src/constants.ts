@@ -8,6 +8,16 @@ export const PROGRAM_ID = new PublicKey(
 export const CONFIG_SEED = Buffer.from("config");
 export const TREASURY_SEED = Buffer.from("treasury");
 export const TASK_SEED = Buffer.from("task");
+export const AGENT_SEED = Buffer.from("agent");
+export const ARBITER_SEED = Buffer.from("arbiter");
+export const DISPUTE_SEED = Buffer.from("dispute");
+export const CATEGORY_SEED = Buffer.from("category");
 
 // 8 bytes for Steel account discriminator
 export const DISCRIMINATOR_SIZE = 8;
+
+// Must match MAX_CLAIMER_PROOF_DEPTH in api/src/consts.rs
+export const MAX_CLAIMER_PROOF_DEPTH = 16;
+
+// Must match MAX_TASK_DEPENDENCIES in api/src/consts.rs
+export const MAX_TASK_DEPENDENCIES = 4;
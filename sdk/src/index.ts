@@ -1,4 +1,4 @@
-export { PROGRAM_ID } from "./constants";
+export { PROGRAM_ID, MAX_CLAIMER_PROOF_DEPTH, MAX_TASK_DEPENDENCIES } from "./constants";
 export { BountyBoardInstruction, TaskStatus } from "./types";
 export {
   createInitializeInstruction,
@@ -12,10 +12,15 @@ export {
   createCancelTaskInstruction,
   createClaimExpiredInstruction,
 } from "./instructions";
+export type { CreateTaskOptions } from "./instructions";
 export {
   getConfigPDA,
   getTreasuryPDA,
   getTaskPDA,
+  getAgentPDA,
+  getArbiterPDA,
+  getDisputePDA,
+  getCategoryPDA,
 } from "./pda";
 export {
   parseConfig,
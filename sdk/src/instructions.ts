@@ -3,9 +3,21 @@ import {
   SystemProgram,
   TransactionInstruction,
 } from "@solana/web3.js";
-import { PROGRAM_ID } from "./constants";
+import { PROGRAM_ID, MAX_CLAIMER_PROOF_DEPTH, MAX_TASK_DEPENDENCIES } from "./constants";
 import { BountyBoardInstruction } from "./types";
-import { getConfigPDA, getTaskPDA, getTreasuryPDA } from "./pda";
+import {
+  getAgentPDA,
+  getArbiterPDA,
+  getCategoryPDA,
+  getConfigPDA,
+  getDisputePDA,
+  getTaskPDA,
+  getTreasuryPDA,
+} from "./pda";
+
+// Builders cover the core task lifecycle for native SOL bounties. SPL token
+// bounties append the mint, escrow and token program accounts listed on each
+// processor in program/src/.
 
 // ============================================================================
 // Helpers
@@ -23,12 +35,30 @@ function encodeU64LE(value: bigint | number): Buffer {
   return buf;
 }
 
+function encodeU32LE(value: number): Buffer {
+  const buf = Buffer.alloc(4);
+  buf.writeUInt32LE(value);
+  return buf;
+}
+
 function encodeI64LE(value: bigint | number): Buffer {
   const buf = Buffer.alloc(8);
   buf.writeBigInt64LE(BigInt(value));
   return buf;
 }
 
+/**
+ * Prerequisite Task PDAs of a dependent task, appended after all other accounts
+ * of CreateTask, and of ClaimTask / CancelTask while the task is unclaimed.
+ */
+function dependencyKeys(dependencies: (bigint | number)[]) {
+  return dependencies.map((id) => ({
+    pubkey: getTaskPDA(id)[0],
+    isSigner: false,
+    isWritable: true,
+  }));
+}
+
 // ============================================================================
 // Instructions
 // ============================================================================
@@ -65,7 +95,29 @@ export function createInitializeInstruction(
 }
 
 /**
- * Create a new task with bounty escrow.
+ * Optional CreateTask settings; see CreateTaskArgs in api/src/instruction.rs.
+ */
+export interface CreateTaskOptions {
+  /** Seconds the claimer has to submit (0 = Config default) */
+  workWindow?: bigint | number;
+  /** Lamports the claimer must stake at ClaimTask */
+  claimBond?: bigint | number;
+  /** TASK_FLAG_* bits */
+  flags?: number;
+  /** Worker slots (0 or 1 = single worker); the bounty is then per slot */
+  slots?: number;
+  /** Only this wallet may claim */
+  allowedClaimer?: PublicKey;
+  /** Merkle root of allowed claimers */
+  claimerRoot?: Uint8Array;
+  minCompletions?: number;
+  minApprovalBps?: number;
+  /** Prerequisite task IDs (the creator's own tasks, at most 4) */
+  dependencies?: (bigint | number)[];
+}
+
+/**
+ * Create a new native SOL task with bounty escrow.
  */
 export function createCreateTaskInstruction(
   creator: PublicKey,
@@ -73,21 +125,48 @@ export function createCreateTaskInstruction(
   bounty: bigint | number,
   descriptionHash: Uint8Array,
   deadline: bigint | number = 0,
-  tags: Uint8Array = new Uint8Array(16)
+  tags: Uint8Array = new Uint8Array(16),
+  options: CreateTaskOptions = {}
 ): TransactionInstruction {
   const [configPDA] = getConfigPDA();
   const [taskPDA] = getTaskPDA(taskId);
+  const [creatorAgentPDA] = getAgentPDA(creator);
+  const [categoryPDA] = getCategoryPDA(Buffer.from(tags).readUInt16LE(0));
 
   if (descriptionHash.length !== 32) throw new Error("descriptionHash must be 32 bytes");
   if (tags.length !== 16) throw new Error("tags must be 16 bytes");
-
-  // CreateTaskArgs: bounty (8) + description_hash (32) + deadline (8) + tags (16) = 64 bytes
+  const claimerRoot = options.claimerRoot ?? new Uint8Array(32);
+  if (claimerRoot.length !== 32) throw new Error("claimerRoot must be 32 bytes");
+  const dependencies = options.dependencies ?? [];
+  if (dependencies.length > MAX_TASK_DEPENDENCIES) {
+    throw new Error(`at most ${MAX_TASK_DEPENDENCIES} dependencies`);
+  }
+
+  // CreateTaskArgs: bounty (8) + description_hash (32) + deadline (8) + tags (16)
+  //   + work_window (8) + claim_bond (8) + flags (1) + padding (3) + slots (4)
+  //   + allowed_claimer (32) + claimer_root (32) + min_completions (4)
+  //   + min_approval_bps (2) + dependency_count (1) + padding (1)
+  //   + dependencies (4 x 8) = 192 bytes
   const data = Buffer.concat([
     Buffer.from([BountyBoardInstruction.CreateTask]),
     encodeU64LE(bounty),
     Buffer.from(descriptionHash),
     encodeI64LE(deadline),
     Buffer.from(tags),
+    encodeI64LE(options.workWindow ?? 0),
+    encodeU64LE(options.claimBond ?? 0),
+    Buffer.from([options.flags ?? 0]),
+    Buffer.alloc(3), // padding
+    encodeU32LE(options.slots ?? 0),
+    (options.allowedClaimer ?? PublicKey.default).toBuffer(),
+    Buffer.from(claimerRoot),
+    encodeU32LE(options.minCompletions ?? 0),
+    encodeU16LE(options.minApprovalBps ?? 0),
+    Buffer.from([dependencies.length]),
+    Buffer.alloc(1), // padding
+    ...Array.from({ length: MAX_TASK_DEPENDENCIES }, (_, i) =>
+      encodeU64LE(dependencies[i] ?? 0)
+    ),
   ]);
 
   return new TransactionInstruction({
@@ -97,23 +176,39 @@ export function createCreateTaskInstruction(
       { pubkey: configPDA, isSigner: false, isWritable: true },
       { pubkey: taskPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: creatorAgentPDA, isSigner: false, isWritable: true },
+      { pubkey: categoryPDA, isSigner: false, isWritable: false },
+      ...dependencyKeys(dependencies),
     ],
     data,
   });
 }
 
 /**
- * Claim an open task.
+ * Claim an open task. Private tasks take the claimer's allowlist proof (sibling
+ * hashes, leaf first); dependent tasks take their prerequisite task IDs.
  */
 export function createClaimTaskInstruction(
   claimer: PublicKey,
-  taskId: bigint | number
+  taskId: bigint | number,
+  proof: Uint8Array[] = [],
+  dependencies: (bigint | number)[] = []
 ): TransactionInstruction {
   const [taskPDA] = getTaskPDA(taskId);
+  const [claimerAgentPDA] = getAgentPDA(claimer);
+
+  if (proof.length > MAX_CLAIMER_PROOF_DEPTH) {
+    throw new Error(`proof deeper than ${MAX_CLAIMER_PROOF_DEPTH}`);
+  }
+  if (proof.some((hash) => hash.length !== 32)) {
+    throw new Error("proof hashes must be 32 bytes");
+  }
 
+  // ClaimTaskArgs: task_id (8), then the proof as trailing data (32 per hash)
   const data = Buffer.concat([
     Buffer.from([BountyBoardInstruction.ClaimTask]),
     encodeU64LE(taskId),
+    ...proof.map((hash) => Buffer.from(hash)),
   ]);
 
   return new TransactionInstruction({
@@ -121,6 +216,9 @@ export function createClaimTaskInstruction(
     keys: [
       { pubkey: claimer, isSigner: true, isWritable: true },
       { pubkey: taskPDA, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: claimerAgentPDA, isSigner: false, isWritable: false },
+      ...dependencyKeys(dependencies),
     ],
     data,
   });
@@ -181,17 +279,21 @@ export function createApproveWorkInstruction(
       { pubkey: claimer, isSigner: false, isWritable: true },
       { pubkey: treasuryPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(creator)[0], isSigner: false, isWritable: true },
+      { pubkey: getAgentPDA(claimer)[0], isSigner: false, isWritable: true },
     ],
     data,
   });
 }
 
 /**
- * Reject submitted work (creator only). Task returns to OPEN.
+ * Reject submitted work (creator only). The claimer can dispute within the
+ * dispute window.
  */
 export function createRejectWorkInstruction(
   creator: PublicKey,
-  taskId: bigint | number
+  taskId: bigint | number,
+  claimer: PublicKey
 ): TransactionInstruction {
   const [taskPDA] = getTaskPDA(taskId);
 
@@ -205,6 +307,7 @@ export function createRejectWorkInstruction(
     keys: [
       { pubkey: creator, isSigner: true, isWritable: true },
       { pubkey: taskPDA, isSigner: false, isWritable: true },
+      { pubkey: getAgentPDA(claimer)[0], isSigner: false, isWritable: true },
     ],
     data,
   });
@@ -212,10 +315,12 @@ export function createRejectWorkInstruction(
 
 /**
  * Dispute a rejection by staking dispute_stake SOL.
+ * @param panel Arbiter indexes assigned to the dispute (see Config::next_panel)
  */
 export function createDisputeInstruction(
   claimer: PublicKey,
-  taskId: bigint | number
+  taskId: bigint | number,
+  panel: number[] = []
 ): TransactionInstruction {
   const [configPDA] = getConfigPDA();
   const [taskPDA] = getTaskPDA(taskId);
@@ -229,22 +334,29 @@ export function createDisputeInstruction(
     programId: PROGRAM_ID,
     keys: [
       { pubkey: claimer, isSigner: true, isWritable: true },
-      { pubkey: configPDA, isSigner: false, isWritable: false },
+      { pubkey: configPDA, isSigner: false, isWritable: true },
       { pubkey: taskPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getDisputePDA(taskId)[0], isSigner: false, isWritable: true },
+      ...panel.map((index) => ({
+        pubkey: getArbiterPDA(index)[0],
+        isSigner: false,
+        isWritable: false,
+      })),
     ],
     data,
   });
 }
 
 /**
- * Admin resolves a dispute.
- * @param winner 0 = creator wins, 1 = claimer wins
+ * Admin resolves a dispute without an arbiter panel.
+ * @param claimerShareBps Claimer's share of the bounty in basis points
+ *   (0 = creator wins, 10000 = claimer wins)
  */
 export function createResolveDisputeInstruction(
   admin: PublicKey,
   taskId: bigint | number,
-  winner: number,
+  claimerShareBps: number,
   claimer: PublicKey,
   creator: PublicKey
 ): TransactionInstruction {
@@ -252,24 +364,27 @@ export function createResolveDisputeInstruction(
   const [taskPDA] = getTaskPDA(taskId);
   const [treasuryPDA] = getTreasuryPDA();
 
-  // ResolveDisputeArgs: task_id (8) + winner (1) + padding (7) = 16 bytes
+  // ResolveDisputeArgs: task_id (8) + claimer_share_bps (2) + padding (6) = 16 bytes
   const data = Buffer.concat([
     Buffer.from([BountyBoardInstruction.ResolveDispute]),
     encodeU64LE(taskId),
-    Buffer.from([winner]),
-    Buffer.alloc(7), // padding
+    encodeU16LE(claimerShareBps),
+    Buffer.alloc(6), // padding
   ]);
 
   return new TransactionInstruction({
     programId: PROGRAM_ID,
     keys: [
       { pubkey: admin, isSigner: true, isWritable: true },
-      { pubkey: configPDA, isSigner: false, isWritable: false },
+      { pubkey: configPDA, isSigner: false, isWritable: true },
       { pubkey: taskPDA, isSigner: false, isWritable: true },
+      { pubkey: getDisputePDA(taskId)[0], isSigner: false, isWritable: true },
       { pubkey: claimer, isSigner: false, isWritable: true },
       { pubkey: creator, isSigner: false, isWritable: true },
       { pubkey: treasuryPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(creator)[0], isSigner: false, isWritable: true },
+      { pubkey: getAgentPDA(claimer)[0], isSigner: false, isWritable: true },
     ],
     data,
   });
@@ -282,6 +397,7 @@ export function createResolveDisputeInstruction(
 export function createClaimExpiredInstruction(
   caller: PublicKey,
   taskId: bigint | number,
+  creator: PublicKey,
   claimer: PublicKey
 ): TransactionInstruction {
   const [configPDA] = getConfigPDA();
@@ -302,17 +418,21 @@ export function createClaimExpiredInstruction(
       { pubkey: claimer, isSigner: false, isWritable: true },
       { pubkey: treasuryPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(creator)[0], isSigner: false, isWritable: true },
+      { pubkey: getAgentPDA(claimer)[0], isSigner: false, isWritable: true },
     ],
     data,
   });
 }
 
 /**
- * Cancel an unclaimed task. Full bounty refund to creator.
+ * Cancel an unclaimed task. Full bounty refund to creator. Dependent tasks that
+ * were never claimed take their prerequisite task IDs.
  */
 export function createCancelTaskInstruction(
   creator: PublicKey,
-  taskId: bigint | number
+  taskId: bigint | number,
+  dependencies: (bigint | number)[] = []
 ): TransactionInstruction {
   const [configPDA] = getConfigPDA();
   const [taskPDA] = getTaskPDA(taskId);
@@ -329,6 +449,7 @@ export function createCancelTaskInstruction(
       { pubkey: configPDA, isSigner: false, isWritable: true },
       { pubkey: taskPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ...dependencyKeys(dependencies),
     ],
     data,
   });
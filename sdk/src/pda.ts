@@ -1,5 +1,14 @@
 import { PublicKey } from "@solana/web3.js";
-import { PROGRAM_ID, CONFIG_SEED, TREASURY_SEED, TASK_SEED } from "./constants";
+import {
+  PROGRAM_ID,
+  CONFIG_SEED,
+  TREASURY_SEED,
+  TASK_SEED,
+  AGENT_SEED,
+  ARBITER_SEED,
+  DISPUTE_SEED,
+  CATEGORY_SEED,
+} from "./constants";
 
 export function getConfigPDA(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([CONFIG_SEED], PROGRAM_ID);
@@ -14,3 +23,25 @@ export function getTaskPDA(taskId: bigint | number): [PublicKey, number] {
   idBuffer.writeBigUInt64LE(BigInt(taskId));
   return PublicKey.findProgramAddressSync([TASK_SEED, idBuffer], PROGRAM_ID);
 }
+
+export function getAgentPDA(wallet: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([AGENT_SEED, wallet.toBuffer()], PROGRAM_ID);
+}
+
+export function getArbiterPDA(index: number): [PublicKey, number] {
+  const indexBuffer = Buffer.alloc(4);
+  indexBuffer.writeUInt32LE(index);
+  return PublicKey.findProgramAddressSync([ARBITER_SEED, indexBuffer], PROGRAM_ID);
+}
+
+export function getDisputePDA(taskId: bigint | number): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(BigInt(taskId));
+  return PublicKey.findProgramAddressSync([DISPUTE_SEED, idBuffer], PROGRAM_ID);
+}
+
+export function getCategoryPDA(categoryId: number): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(2);
+  idBuffer.writeUInt16LE(categoryId);
+  return PublicKey.findProgramAddressSync([CATEGORY_SEED, idBuffer], PROGRAM_ID);
+}
@@ -1,10 +1,10 @@
 import { PublicKey } from "@solana/web3.js";
-import { DISCRIMINATOR_SIZE } from "./constants";
+import { DISCRIMINATOR_SIZE, MAX_TASK_DEPENDENCIES } from "./constants";
 import { ConfigAccount, TaskAccount, TaskStatus } from "./types";
 
 /**
  * Parse a Config account from raw account data.
- * Layout (after 8-byte Steel discriminator):
+ * Layout (after 8-byte Steel discriminator), 192 bytes:
  *   admin: 32 bytes (Pubkey)
  *   protocol_fee_bps: 2 bytes (u16 LE)
  *   _padding: 6 bytes
@@ -13,6 +13,16 @@ import { ConfigAccount, TaskAccount, TaskStatus } from "./types";
  *   total_escrowed: 8 bytes (u64 LE)
  *   total_completed: 8 bytes (u64 LE)
  *   dispute_stake: 8 bytes (u64 LE)
+ *   default_work_window: 8 bytes (i64 LE)
+ *   pending_admin: 32 bytes (Pubkey)
+ *   total_fees_collected: 8 bytes (u64 LE)
+ *   arbiter_count: 4 bytes (u32 LE)
+ *   arbiters_per_dispute: 1 byte (u8)
+ *   _padding2: 3 bytes
+ *   arbiter_cursor: 8 bytes (u64 LE)
+ *   dispute_resolution_timeout: 8 bytes (i64 LE)
+ *   template_count: 8 bytes (u64 LE)
+ *   vault_count: 8 bytes (u64 LE)
  */
 export function parseConfig(data: Buffer): ConfigAccount {
   let offset = DISCRIMINATOR_SIZE;
@@ -39,6 +49,36 @@ export function parseConfig(data: Buffer): ConfigAccount {
   offset += 8;
 
   const disputeStake = data.readBigUInt64LE(offset);
+  offset += 8;
+
+  const defaultWorkWindow = data.readBigInt64LE(offset);
+  offset += 8;
+
+  const pendingAdmin = new PublicKey(data.subarray(offset, offset + 32));
+  offset += 32;
+
+  const totalFeesCollected = data.readBigUInt64LE(offset);
+  offset += 8;
+
+  const arbiterCount = data.readUInt32LE(offset);
+  offset += 4;
+
+  const arbitersPerDispute = data.readUInt8(offset);
+  offset += 1;
+
+  // skip padding
+  offset += 3;
+
+  const arbiterCursor = data.readBigUInt64LE(offset);
+  offset += 8;
+
+  const disputeResolutionTimeout = data.readBigInt64LE(offset);
+  offset += 8;
+
+  const templateCount = data.readBigUInt64LE(offset);
+  offset += 8;
+
+  const vaultCount = data.readBigUInt64LE(offset);
 
   return {
     admin,
@@ -48,12 +88,21 @@ export function parseConfig(data: Buffer): ConfigAccount {
     totalEscrowed,
     totalCompleted,
     disputeStake,
+    defaultWorkWindow,
+    pendingAdmin,
+    totalFeesCollected,
+    arbiterCount,
+    arbitersPerDispute,
+    arbiterCursor,
+    disputeResolutionTimeout,
+    templateCount,
+    vaultCount,
   };
 }
 
 /**
  * Parse a Task account from raw account data.
- * Layout (after 8-byte Steel discriminator):
+ * Layout (after 8-byte Steel discriminator), 448 bytes:
  *   id: 8 bytes (u64 LE)
  *   creator: 32 bytes (Pubkey)
  *   claimer: 32 bytes (Pubkey)
@@ -61,10 +110,42 @@ export function parseConfig(data: Buffer): ConfigAccount {
  *   description_hash: 32 bytes
  *   proof_hash: 32 bytes
  *   status: 1 byte (u8)
- *   _padding: 7 bytes
+ *   milestone_count: 1 byte (u8)
+ *   milestones_released: 1 byte (u8)
+ *   flags: 1 byte (u8)
+ *   slots_closed: 1 byte (u8)
+ *   _padding: 3 bytes
  *   created_at: 8 bytes (i64 LE)
  *   deadline: 8 bytes (i64 LE)
  *   tags: 16 bytes
+ *   submitted_at: 8 bytes (i64 LE)
+ *   claimed_at: 8 bytes (i64 LE)
+ *   mint: 32 bytes (Pubkey)
+ *   rejected_at: 8 bytes (i64 LE)
+ *   milestones_total: 8 bytes (u64 LE)
+ *   released: 8 bytes (u64 LE)
+ *   work_window: 8 bytes (i64 LE)
+ *   claim_bond: 8 bytes (u64 LE)
+ *   entries: 8 bytes (u64 LE)
+ *   slots: 4 bytes (u32 LE)
+ *   slots_taken: 4 bytes (u32 LE)
+ *   slots_done: 4 bytes (u32 LE)
+ *   next_slot: 4 bytes (u32 LE)
+ *   slots_rejected: 4 bytes (u32 LE)
+ *   _padding2: 4 bytes
+ *   bounty_per_slot: 8 bytes (u64 LE)
+ *   contributed: 8 bytes (u64 LE)
+ *   contributed_open: 8 bytes (u64 LE)
+ *   refund_pool: 8 bytes (u64 LE)
+ *   contributors: 4 bytes (u32 LE)
+ *   dependents: 4 bytes (u32 LE)
+ *   allowed_claimer: 32 bytes (Pubkey)
+ *   claimer_root: 32 bytes
+ *   min_completions: 4 bytes (u32 LE)
+ *   min_approval_bps: 2 bytes (u16 LE)
+ *   dependency_count: 1 byte (u8)
+ *   prerequisites_held: 1 byte (u8)
+ *   dependencies: 4 x 8 bytes (u64 LE)
  */
 export function parseTask(data: Buffer): TaskAccount {
   let offset = DISCRIMINATOR_SIZE;
@@ -90,8 +171,20 @@ export function parseTask(data: Buffer): TaskAccount {
   const status = data.readUInt8(offset) as TaskStatus;
   offset += 1;
 
+  const milestoneCount = data.readUInt8(offset);
+  offset += 1;
+
+  const milestonesReleased = data.readUInt8(offset);
+  offset += 1;
+
+  const flags = data.readUInt8(offset);
+  offset += 1;
+
+  const slotsClosed = data.readUInt8(offset) !== 0;
+  offset += 1;
+
   // skip padding
-  offset += 7;
+  offset += 3;
 
   const createdAt = data.readBigInt64LE(offset);
   offset += 8;
@@ -106,6 +199,87 @@ export function parseTask(data: Buffer): TaskAccount {
   offset += 8;
 
   const claimedAt = data.readBigInt64LE(offset);
+  offset += 8;
+
+  const mint = new PublicKey(data.subarray(offset, offset + 32));
+  offset += 32;
+
+  const rejectedAt = data.readBigInt64LE(offset);
+  offset += 8;
+
+  const milestonesTotal = data.readBigUInt64LE(offset);
+  offset += 8;
+
+  const released = data.readBigUInt64LE(offset);
+  offset += 8;
+
+  const workWindow = data.readBigInt64LE(offset);
+  offset += 8;
+
+  const claimBond = data.readBigUInt64LE(offset);
+  offset += 8;
+
+  const entries = data.readBigUInt64LE(offset);
+  offset += 8;
+
+  const slots = data.readUInt32LE(offset);
+  offset += 4;
+
+  const slotsTaken = data.readUInt32LE(offset);
+  offset += 4;
+
+  const slotsDone = data.readUInt32LE(offset);
+  offset += 4;
+
+  const nextSlot = data.readUInt32LE(offset);
+  offset += 4;
+
+  const slotsRejected = data.readUInt32LE(offset);
+  offset += 4;
+
+  // skip padding
+  offset += 4;
+
+  const bountyPerSlot = data.readBigUInt64LE(offset);
+  offset += 8;
+
+  const contributed = data.readBigUInt64LE(offset);
+  offset += 8;
+
+  const contributedOpen = data.readBigUInt64LE(offset);
+  offset += 8;
+
+  const refundPool = data.readBigUInt64LE(offset);
+  offset += 8;
+
+  const contributors = data.readUInt32LE(offset);
+  offset += 4;
+
+  const dependents = data.readUInt32LE(offset);
+  offset += 4;
+
+  const allowedClaimer = new PublicKey(data.subarray(offset, offset + 32));
+  offset += 32;
+
+  const claimerRoot = new Uint8Array(data.subarray(offset, offset + 32));
+  offset += 32;
+
+  const minCompletions = data.readUInt32LE(offset);
+  offset += 4;
+
+  const minApprovalBps = data.readUInt16LE(offset);
+  offset += 2;
+
+  const dependencyCount = Math.min(data.readUInt8(offset), MAX_TASK_DEPENDENCIES);
+  offset += 1;
+
+  const prerequisitesHeld = data.readUInt8(offset) !== 0;
+  offset += 1;
+
+  const dependencies: bigint[] = [];
+  for (let i = 0; i < dependencyCount; i++) {
+    dependencies.push(data.readBigUInt64LE(offset + i * 8));
+  }
 
   return {
     id,
@@ -115,10 +289,38 @@ export function parseTask(data: Buffer): TaskAccount {
     descriptionHash,
     proofHash,
     status,
+    milestoneCount,
+    milestonesReleased,
+    flags,
+    slotsClosed,
     createdAt,
     deadline,
     tags,
     submittedAt,
     claimedAt,
+    mint,
+    rejectedAt,
+    milestonesTotal,
+    released,
+    workWindow,
+    claimBond,
+    entries,
+    slots,
+    slotsTaken,
+    slotsDone,
+    nextSlot,
+    slotsRejected,
+    bountyPerSlot,
+    contributed,
+    contributedOpen,
+    refundPool,
+    contributors,
+    dependents,
+    allowedClaimer,
+    claimerRoot,
+    minCompletions,
+    minApprovalBps,
+    prerequisitesHeld,
+    dependencies,
   };
 }
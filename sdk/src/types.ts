@@ -11,6 +11,53 @@ export enum BountyBoardInstruction {
   ResolveDispute = 7,
   CancelTask = 8,
   ClaimExpired = 9,
+  RegisterAgent = 10,
+  ReopenTask = 11,
+  AddMilestone = 12,
+  SubmitMilestone = 13,
+  ApproveMilestone = 14,
+  RejectMilestone = 15,
+  ReleaseStaleClaim = 16,
+  UpdateConfig = 17,
+  ProposeAdmin = 18,
+  AcceptAdmin = 19,
+  WithdrawTreasury = 20,
+  CloseTask = 21,
+  SubmitApplication = 22,
+  AcceptApplication = 23,
+  CloseApplication = 24,
+  SubmitEntry = 25,
+  AwardContest = 26,
+  CloseSubmission = 27,
+  ClaimSlot = 28,
+  SubmitSlot = 29,
+  ApproveSlot = 30,
+  RejectSlot = 31,
+  AddArbiter = 32,
+  RemoveArbiter = 33,
+  CastVote = 34,
+  FinalizeDispute = 35,
+  ExpireDispute = 36,
+  CounterStake = 37,
+  SubmitEvidence = 38,
+  ExpireTask = 39,
+  IncreaseBounty = 40,
+  UpdateTask = 41,
+  WithdrawContribution = 42,
+  AddCategory = 43,
+  UpdateCategory = 44,
+  CreateTemplate = 45,
+  UpdateTemplate = 46,
+  CreateTaskFromTemplate = 47,
+  CreateVault = 48,
+  DepositVault = 49,
+  WithdrawVault = 50,
+  CloseVault = 51,
+  SpawnScheduledTask = 52,
+  ReleaseStaleSlot = 53,
+  ClaimExpiredSlot = 54,
+  ClaimExpiredMilestone = 55,
+  ClaimContestShare = 56,
 }
 
 export enum TaskStatus {
@@ -20,6 +67,7 @@ export enum TaskStatus {
   Completed = 3,
   Cancelled = 4,
   Disputed = 5,
+  Rejected = 6,
 }
 
 export interface ConfigAccount {
@@ -30,6 +78,15 @@ export interface ConfigAccount {
   totalEscrowed: bigint;
   totalCompleted: bigint;
   disputeStake: bigint;
+  defaultWorkWindow: bigint;
+  pendingAdmin: PublicKey;
+  totalFeesCollected: bigint;
+  arbiterCount: number;
+  arbitersPerDispute: number;
+  arbiterCursor: bigint;
+  disputeResolutionTimeout: bigint;
+  templateCount: bigint;
+  vaultCount: bigint;
 }
 
 export interface TaskAccount {
@@ -40,9 +97,39 @@ export interface TaskAccount {
   descriptionHash: Uint8Array;
   proofHash: Uint8Array;
   status: TaskStatus;
+  milestoneCount: number;
+  milestonesReleased: number;
+  flags: number;
+  slotsClosed: boolean;
   createdAt: bigint;
   deadline: bigint;
   tags: Uint8Array;
   submittedAt: bigint;
   claimedAt: bigint;
+  /** Bounty mint (default public key for native SOL) */
+  mint: PublicKey;
+  rejectedAt: bigint;
+  milestonesTotal: bigint;
+  released: bigint;
+  workWindow: bigint;
+  claimBond: bigint;
+  entries: bigint;
+  slots: number;
+  slotsTaken: number;
+  slotsDone: number;
+  nextSlot: number;
+  slotsRejected: number;
+  bountyPerSlot: bigint;
+  contributed: bigint;
+  contributedOpen: bigint;
+  refundPool: bigint;
+  contributors: number;
+  dependents: number;
+  allowedClaimer: PublicKey;
+  claimerRoot: Uint8Array;
+  minCompletions: number;
+  minApprovalBps: number;
+  prerequisitesHeld: boolean;
+  /** Prerequisite task IDs (the first `dependency_count` entries) */
+  dependencies: bigint[];
 }
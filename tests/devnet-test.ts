@@ -27,6 +27,8 @@ const PROGRAM_ID = new PublicKey("GJgmGsoz1JaiPpKTTTeZD31TrxZqF7x7gtwuqhDJHHX1")
 const CONFIG_SEED = Buffer.from("config");
 const TREASURY_SEED = Buffer.from("treasury");
 const TASK_SEED = Buffer.from("task");
+const AGENT_SEED = Buffer.from("agent");
+const CATEGORY_SEED = Buffer.from("category");
 const DISCRIMINATOR_SIZE = 8;
 
 const DEVNET_URL = "https://api.devnet.solana.com";
@@ -50,6 +52,16 @@ function getTaskPDA(taskId: bigint | number): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([TASK_SEED, buf], PROGRAM_ID);
 }
 
+function getAgentPDA(wallet: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([AGENT_SEED, wallet.toBuffer()], PROGRAM_ID);
+}
+
+function getCategoryPDA(categoryId: number): [PublicKey, number] {
+  const buf = Buffer.alloc(2);
+  buf.writeUInt16LE(categoryId);
+  return PublicKey.findProgramAddressSync([CATEGORY_SEED, buf], PROGRAM_ID);
+}
+
 // ============================================================================
 // Encoding helpers
 // ============================================================================
@@ -99,7 +111,12 @@ interface TaskAccount {
   tags: Uint8Array;
 }
 
+// Current account sizes; older accounts need a redeploy (see the README)
+const CONFIG_SIZE = DISCRIMINATOR_SIZE + 192;
+const TASK_SIZE = DISCRIMINATOR_SIZE + 448;
+
 function parseConfig(data: Buffer): ConfigAccount {
+  if (data.length !== CONFIG_SIZE) throw new Error(`Config is ${data.length} bytes, expected ${CONFIG_SIZE}`);
   let o = DISCRIMINATOR_SIZE;
   const admin = new PublicKey(data.subarray(o, o + 32)); o += 32;
   const protocolFeeBps = data.readUInt16LE(o); o += 2;
@@ -113,6 +130,7 @@ function parseConfig(data: Buffer): ConfigAccount {
 }
 
 function parseTask(data: Buffer): TaskAccount {
+  if (data.length !== TASK_SIZE) throw new Error(`Task is ${data.length} bytes, expected ${TASK_SIZE}`);
   let o = DISCRIMINATOR_SIZE;
   const id = data.readBigUInt64LE(o); o += 8;
   const creator = new PublicKey(data.subarray(o, o + 32)); o += 32;
@@ -129,7 +147,7 @@ function parseTask(data: Buffer): TaskAccount {
 }
 
 const STATUS_NAMES: Record<number, string> = {
-  0: "Open", 1: "Claimed", 2: "Submitted", 3: "Completed", 4: "Cancelled", 5: "Disputed",
+  0: "Open", 1: "Claimed", 2: "Submitted", 3: "Completed", 4: "Cancelled", 5: "Disputed", 6: "Rejected",
 };
 
 // ============================================================================
@@ -161,8 +179,19 @@ function ixCreateTask(creator: PublicKey, taskId: bigint, bounty: bigint, descHa
       { pubkey: configPDA, isSigner: false, isWritable: true },
       { pubkey: taskPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(creator)[0], isSigner: false, isWritable: true },
+      { pubkey: getCategoryPDA(Buffer.from(tags).readUInt16LE(0))[0], isSigner: false, isWritable: false },
     ],
-    data: Buffer.concat([Buffer.from([1]), encodeU64LE(bounty), Buffer.from(descHash), encodeI64LE(deadline), Buffer.from(tags)]),
+    data: Buffer.concat([
+      Buffer.from([1]),
+      encodeU64LE(bounty),
+      Buffer.from(descHash),
+      encodeI64LE(deadline),
+      Buffer.from(tags),
+      // work_window, claim_bond, flags + padding, slots, allowed_claimer, claimer_root,
+      // min_completions, min_approval_bps, dependency_count + padding, dependencies
+      Buffer.alloc(8 + 8 + 4 + 4 + 32 + 32 + 4 + 2 + 2 + 32),
+    ]),
   });
 }
 
@@ -173,6 +202,8 @@ function ixClaimTask(claimer: PublicKey, taskId: bigint): TransactionInstruction
     keys: [
       { pubkey: claimer, isSigner: true, isWritable: true },
       { pubkey: taskPDA, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(claimer)[0], isSigner: false, isWritable: false },
     ],
     data: Buffer.concat([Buffer.from([2]), encodeU64LE(taskId)]),
   });
@@ -203,6 +234,8 @@ function ixApproveWork(creator: PublicKey, taskId: bigint, claimer: PublicKey):
       { pubkey: claimer, isSigner: false, isWritable: true },
       { pubkey: treasuryPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(creator)[0], isSigner: false, isWritable: true },
+      { pubkey: getAgentPDA(claimer)[0], isSigner: false, isWritable: true },
     ],
     data: Buffer.concat([Buffer.from([4]), encodeU64LE(taskId)]),
   });
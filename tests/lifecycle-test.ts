@@ -13,6 +13,7 @@ const PROGRAM_ID = new PublicKey("GJgmGsoz1JaiPpKTTTeZD31TrxZqF7x7gtwuqhDJHHX1")
 const CONFIG_SEED = Buffer.from("config");
 const TASK_SEED = Buffer.from("task");
 const TREASURY_SEED = Buffer.from("treasury");
+const AGENT_SEED = Buffer.from("agent");
 
 function getConfigPDA(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([CONFIG_SEED], PROGRAM_ID);
@@ -25,6 +26,9 @@ function getTaskPDA(taskId: bigint | number): [PublicKey, number] {
 function getTreasuryPDA(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([TREASURY_SEED], PROGRAM_ID);
 }
+function getAgentPDA(wallet: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([AGENT_SEED, wallet.toBuffer()], PROGRAM_ID);
+}
 
 function encodeU64LE(v: bigint | number): Buffer {
   const b = Buffer.alloc(8); b.writeBigUInt64LE(BigInt(v)); return b;
@@ -69,6 +73,8 @@ async function main() {
     keys: [
       { pubkey: claimer.publicKey, isSigner: true, isWritable: true },
       { pubkey: taskPDA, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(claimer.publicKey)[0], isSigner: false, isWritable: false },
     ],
     data: Buffer.concat([Buffer.from([2]), encodeU64LE(taskId)]),
   });
@@ -117,6 +123,8 @@ async function main() {
       { pubkey: claimer.publicKey, isSigner: false, isWritable: true },
       { pubkey: treasuryPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(admin.publicKey)[0], isSigner: false, isWritable: true },
+      { pubkey: getAgentPDA(claimer.publicKey)[0], isSigner: false, isWritable: true },
     ],
     data: Buffer.concat([Buffer.from([4]), encodeU64LE(taskId)]),
   });
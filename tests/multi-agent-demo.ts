@@ -21,6 +21,7 @@ const PROGRAM_ID = new PublicKey("GJgmGsoz1JaiPpKTTTeZD31TrxZqF7x7gtwuqhDJHHX1")
 const CONFIG_SEED = Buffer.from("config");
 const TASK_SEED = Buffer.from("task");
 const TREASURY_SEED = Buffer.from("treasury");
+const AGENT_SEED = Buffer.from("agent");
 const HELIUS_RPC = "https://devnet.helius-rpc.com/?api-key=06cda3a9-32f3-4ad9-a203-9d7274299837";
 
 function getConfigPDA(): [PublicKey, number] {
@@ -34,6 +35,9 @@ function getTaskPDA(taskId: bigint | number): [PublicKey, number] {
 function getTreasuryPDA(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([TREASURY_SEED], PROGRAM_ID);
 }
+function getAgentPDA(wallet: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([AGENT_SEED, wallet.toBuffer()], PROGRAM_ID);
+}
 function encodeU64LE(v: bigint | number): Buffer {
   const b = Buffer.alloc(8); b.writeBigUInt64LE(BigInt(v)); return b;
 }
@@ -94,6 +98,8 @@ async function main() {
     keys: [
       { pubkey: agentB.publicKey, isSigner: true, isWritable: true },
       { pubkey: taskPdaB, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(agentB.publicKey)[0], isSigner: false, isWritable: false },
     ],
     data: Buffer.concat([Buffer.from([2]), encodeU64LE(taskIdB)]),
   });
@@ -122,6 +128,8 @@ async function main() {
       { pubkey: agentB.publicKey, isSigner: false, isWritable: true },
       { pubkey: treasuryPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(admin.publicKey)[0], isSigner: false, isWritable: true },
+      { pubkey: getAgentPDA(agentB.publicKey)[0], isSigner: false, isWritable: true },
     ],
     data: Buffer.concat([Buffer.from([4]), encodeU64LE(taskIdB)]),
   });
@@ -137,6 +145,8 @@ async function main() {
     keys: [
       { pubkey: agentC.publicKey, isSigner: true, isWritable: true },
       { pubkey: taskPdaC, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(agentC.publicKey)[0], isSigner: false, isWritable: false },
     ],
     data: Buffer.concat([Buffer.from([2]), encodeU64LE(taskIdC)]),
   });
@@ -164,6 +174,8 @@ async function main() {
       { pubkey: agentC.publicKey, isSigner: false, isWritable: true },
       { pubkey: treasuryPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(admin.publicKey)[0], isSigner: false, isWritable: true },
+      { pubkey: getAgentPDA(agentC.publicKey)[0], isSigner: false, isWritable: true },
     ],
     data: Buffer.concat([Buffer.from([4]), encodeU64LE(taskIdC)]),
   });
@@ -179,6 +191,8 @@ async function main() {
     keys: [
       { pubkey: agentC.publicKey, isSigner: true, isWritable: true },
       { pubkey: taskPdaC2, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(agentC.publicKey)[0], isSigner: false, isWritable: false },
     ],
     data: Buffer.concat([Buffer.from([2]), encodeU64LE(taskIdC2)]),
   });
@@ -18,6 +18,8 @@ import * as crypto from "crypto";
 const PROGRAM_ID = new PublicKey("GJgmGsoz1JaiPpKTTTeZD31TrxZqF7x7gtwuqhDJHHX1");
 const CONFIG_SEED = Buffer.from("config");
 const TASK_SEED = Buffer.from("task");
+const AGENT_SEED = Buffer.from("agent");
+const CATEGORY_SEED = Buffer.from("category");
 
 function getConfigPDA(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([CONFIG_SEED], PROGRAM_ID);
@@ -29,6 +31,16 @@ function getTaskPDA(taskId: bigint | number): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([TASK_SEED, buf], PROGRAM_ID);
 }
 
+function getAgentPDA(wallet: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([AGENT_SEED, wallet.toBuffer()], PROGRAM_ID);
+}
+
+function getCategoryPDA(categoryId: number): [PublicKey, number] {
+  const buf = Buffer.alloc(2);
+  buf.writeUInt16LE(categoryId);
+  return PublicKey.findProgramAddressSync([CATEGORY_SEED, buf], PROGRAM_ID);
+}
+
 function encodeU64LE(v: bigint | number): Buffer {
   const b = Buffer.alloc(8);
   b.writeBigUInt64LE(BigInt(v));
@@ -67,6 +79,8 @@ function ixCreateTask(
       { pubkey: configPDA, isSigner: false, isWritable: true },
       { pubkey: taskPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: getAgentPDA(creator)[0], isSigner: false, isWritable: true },
+      { pubkey: getCategoryPDA(Buffer.from(tags).readUInt16LE(0))[0], isSigner: false, isWritable: false },
     ],
     data: Buffer.concat([
       Buffer.from([1]),
@@ -74,6 +88,9 @@ function ixCreateTask(
       Buffer.from(descHash),
       encodeI64LE(deadline),
       Buffer.from(tags),
+      // work_window, claim_bond, flags + padding, slots, allowed_claimer, claimer_root,
+      // min_completions, min_approval_bps, dependency_count + padding, dependencies
+      Buffer.alloc(8 + 8 + 4 + 4 + 32 + 32 + 4 + 2 + 2 + 32),
     ]),
   });
 }